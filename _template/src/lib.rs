@@ -1,29 +1,40 @@
-pub fn part1(input: &Vec<&str>) -> usize {
-    0
-}
+use helpers::{input_lines, Solution};
+
+pub struct DayN;
+
+impl Solution for DayN {
+    type Input<'a> = Vec<&'a str>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input_lines(input)
+    }
 
-pub fn part2(input: &Vec<&str>) -> usize {
-    0
+    fn part1(input: &Self::Input<'_>) -> usize {
+        0
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        0
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use helpers::input_lines;
-
     use super::*;
 
     fn input<'a>() -> Vec<&'a str> {
         let input = "";
-        input_lines(input)
+        DayN::parse(input)
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(&input()), 0)
+        assert_eq!(DayN::part1(&input()), 0)
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(&input()), 0)
+        assert_eq!(DayN::part2(&input()), 0)
     }
 }
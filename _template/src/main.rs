@@ -1,8 +1,11 @@
-use helpers::{input_lines, run};
+use dayN::DayN;
+use helpers::{day, input};
 
 fn main() {
-    let input: Vec<&str> = input_lines(include_str!("../input.txt"));
+    let input = input!();
+    let mut day = day!();
 
-    run("part1", || dayN::part1(&input));
-    run("part2", || dayN::part2(&input));
+    day.solve::<DayN>(&input);
+
+    day.finish();
 }
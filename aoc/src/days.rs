@@ -0,0 +1,74 @@
+use helpers::{Day, InputSource, LoadError};
+
+pub struct Entry {
+    pub number: u8,
+    pub name: &'static str,
+    pub dir: &'static str,
+    pub embedded: Option<&'static str>,
+    pub solve: fn(&mut Day, &str),
+}
+
+impl Entry {
+    pub fn day(&self) -> Result<Day, String> {
+        Day::try_new(self.name, self.dir)
+    }
+
+    pub fn input(&self, args: &[String]) -> Result<String, LoadError> {
+        match self.embedded {
+            Some(input) if args.is_empty() => Ok(input.to_string()),
+            _ => InputSource::resolve(args, None, self.dir)?.read(),
+        }
+    }
+}
+
+#[cfg(feature = "embed")]
+macro_rules! embedded {
+    ($name:ident) => {
+        Some(include_str!(concat!(
+            "../../",
+            stringify!($name),
+            "/input.txt"
+        )))
+    };
+}
+
+#[cfg(not(feature = "embed"))]
+macro_rules! embedded {
+    ($name:ident) => {
+        None
+    };
+}
+
+macro_rules! entry {
+    ($number:literal, $name:ident, $solve:expr) => {
+        Entry {
+            number: $number,
+            name: stringify!($name),
+            dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../", stringify!($name)),
+            embedded: embedded!($name),
+            solve: $solve,
+        }
+    };
+}
+
+macro_rules! solution {
+    ($solution:ty) => {
+        |day: &mut Day, input: &str| day.solve::<$solution>(input)
+    };
+}
+
+pub fn all() -> Vec<Entry> {
+    vec![
+        entry!(1, day1, solution!(day1::Day1)),
+        entry!(2, day2, solution!(day2::Day2)),
+        entry!(3, day3, solution!(day3::Day3)),
+        entry!(4, day4, solution!(day4::Day4)),
+        entry!(5, day5, solution!(day5::Day5)),
+        entry!(6, day6, solution!(day6::Day6)),
+        entry!(7, day7, solution!(day7::Day7)),
+        entry!(8, day8, solution!(day8::Day8)),
+        entry!(9, day9, solution!(day9::Day9)),
+        entry!(10, day10, solution!(day10::Day10)),
+        entry!(11, day11, solution!(day11::Day11)),
+    ]
+}
@@ -0,0 +1,364 @@
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Instant, SystemTime};
+
+use helpers::{
+    input_hash, parse_timeout, submit_answer, Client, Download, Format, History, Outcome,
+    Regression, RunReport, Timing, DEFAULT_REGRESSION_THRESHOLD, HISTORY_FILE, INPUT_FILE,
+};
+
+mod days;
+mod pool;
+mod scaffold;
+mod selection;
+mod table;
+
+use selection::Selection;
+
+const USAGE: &str = "\
+Usage: aoc <command> [options]
+
+Commands:
+  run [DAYS] [--format FORMAT] [--input PATH] [--timeout LIMIT] [--jobs N]
+      Run the selected days (`7`, `3..=9`, `1,4` or `all`); --input only applies to a single day,
+      --timeout (`30`, `10s`, `500ms`) limits each part
+      Days and the two parts of a day run on N threads (default: all cores, 1 runs sequentially)
+      [--threshold PCT] [--fail-on-regression] [--no-history]
+      Timings are appended to timings.log and parts slower than the best run for the same input by
+      more than PCT percent (default 10) are flagged, failing the run with --fail-on-regression
+  new DAY [--grid]
+      Create dayN from _template (with input_grid boilerplate when --grid is given) and register it
+  download DAY
+      Fetch the day's input into dayN/input.txt using AOC_SESSION or ~/.config/aoc/session
+  submit DAY PART [ANSWER]
+      Submit an answer (runs the day when ANSWER is omitted) and record it in dayN/answers.toml
+
+Formats: text (default), json, csv, markdown";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(|arg| arg.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("new") => new(&args[1..]),
+        Some("download") => download(&args[1..]),
+        Some("submit") => submit(&args[1..]),
+        Some("help") | Some("--help") | Some("-h") => {
+            println!("{}", USAGE);
+            Ok(true)
+        }
+        Some(command) => Err(format!("Unknown command `{}`\n\n{}", command, USAGE)),
+        None => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(2);
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<bool, String> {
+    let mut selection = Selection::All;
+    let mut format = Format::Text;
+    let mut input_args: Vec<String> = vec![];
+    let mut timeout = None;
+    let mut record = true;
+    let mut threshold = DEFAULT_REGRESSION_THRESHOLD;
+    let mut fail_on_regression = false;
+    let mut jobs = pool::default_jobs();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" | "-f" => {
+                format = args.next().ok_or("Missing value for --format")?.parse()?;
+            }
+            "--input" | "-i" => {
+                let path = args.next().ok_or("Missing value for --input")?;
+                input_args = vec!["--input".to_string(), path.clone()];
+            }
+            "--timeout" | "-t" => {
+                let limit = args.next().ok_or("Missing value for --timeout")?;
+                timeout = Some(parse_timeout(limit)?);
+            }
+            "--no-history" => record = false,
+            "--threshold" => {
+                let value = args.next().ok_or("Missing value for --threshold")?;
+                threshold = value
+                    .trim_end_matches('%')
+                    .parse()
+                    .ok()
+                    .filter(|threshold: &f64| *threshold >= 0.0)
+                    .ok_or_else(|| format!("Invalid threshold `{}`", value))?;
+            }
+            "--fail-on-regression" => fail_on_regression = true,
+            "--jobs" | "-j" => {
+                let value = args.next().ok_or("Missing value for --jobs")?;
+                jobs = value
+                    .parse()
+                    .ok()
+                    .filter(|jobs| *jobs > 0)
+                    .ok_or_else(|| format!("Invalid number of jobs `{}`", value))?;
+            }
+            value => selection = value.parse()?,
+        }
+    }
+
+    let entries: Vec<_> = days::all()
+        .into_iter()
+        .filter(|entry| selection.contains(entry.number))
+        .collect();
+
+    if entries.is_empty() {
+        return Err("No registered day matches the selection".to_string());
+    }
+
+    if !input_args.is_empty() && entries.len() > 1 {
+        return Err("--input can only be used when running a single day".to_string());
+    }
+
+    let history_path = root().join(HISTORY_FILE);
+    let mut history = if record {
+        Some(
+            History::load(&history_path)
+                .map_err(|error| format!("{}: {}", history_path.display(), error))?,
+        )
+    } else {
+        None
+    };
+
+    let start = Instant::now();
+    let runs = pool::map(&entries, jobs, |entry| -> Result<_, String> {
+        let input = entry
+            .input(&input_args)
+            .map_err(|error| format!("{}: {}", entry.name, error))?;
+
+        let mut day = entry
+            .day()
+            .map_err(|error| format!("{}: {}", entry.name, error))?
+            .quiet();
+        if let Some(limit) = timeout {
+            day = day.timeout(limit);
+        }
+        // Several days already keep the pool busy, so only a lone day splits its parts.
+        if jobs > 1 && entries.len() == 1 {
+            day = day.parallel();
+        }
+        let completed =
+            panic::catch_unwind(AssertUnwindSafe(|| (entry.solve)(&mut day, &input))).is_ok();
+
+        Ok((input_hash(&input), day.reports().to_vec(), completed))
+    });
+    let wall_time = start.elapsed();
+
+    let mut reports: Vec<RunReport> = vec![];
+    let mut regressions: Vec<(String, Regression)> = vec![];
+    let mut success = true;
+
+    // Results come back in calendar order, so history and output stay deterministic.
+    for (entry, run) in entries.iter().zip(runs) {
+        let (hash, day_reports, completed) = match run {
+            Ok(run) => run,
+            Err(error) => {
+                eprintln!("{}", error);
+                success = false;
+                continue;
+            }
+        };
+
+        if !completed {
+            eprintln!("{}: panicked outside of a part", entry.name);
+            success = false;
+        }
+
+        if let Some(history) = history.as_mut() {
+            let now = SystemTime::now();
+
+            for report in &day_reports {
+                let Some(timing) = Timing::from_report(report, hash, jobs > 1, now) else {
+                    continue;
+                };
+
+                if let Some(regression) = history.check(&timing, threshold) {
+                    regressions.push((report.name(), regression));
+                }
+                history
+                    .append(timing)
+                    .map_err(|error| format!("{}: {}", history_path.display(), error))?;
+            }
+        }
+
+        reports.extend(day_reports);
+    }
+
+    if !reports.is_empty() {
+        match format {
+            Format::Text => print!("{}", table::render(&reports, wall_time)),
+            format => print!("{}", format.render(&reports)),
+        }
+    }
+
+    for report in &reports {
+        if let Some(error) = &report.input_error {
+            eprint!("{}: {}", report.name(), error.render());
+        }
+    }
+    for (name, regression) in &regressions {
+        eprintln!("{}: {}", name, regression);
+    }
+    if fail_on_regression && !regressions.is_empty() {
+        success = false;
+    }
+
+    Ok(success && !reports.iter().any(|report| report.is_failure()))
+}
+
+fn new(args: &[String]) -> Result<bool, String> {
+    let day = parse_day(args.first())?;
+    let kind = match args.get(1).map(|arg| arg.as_str()) {
+        None | Some("--lines") => scaffold::Kind::Lines,
+        Some("--grid") => scaffold::Kind::Grid,
+        Some(arg) => return Err(format!("Unknown option `{}`", arg)),
+    };
+
+    let dir = scaffold::create_day(root(), day, kind)?;
+    println!("Created {}", dir.display());
+
+    Ok(true)
+}
+
+fn download(args: &[String]) -> Result<bool, String> {
+    let day = parse_day(args.first())?;
+    let dir = day_dir(day);
+
+    if !dir.is_dir() {
+        return Err(format!("{} does not exist", dir.display()));
+    }
+
+    let client = Client::from_env().map_err(|error| error.to_string())?;
+    let path = dir.join(INPUT_FILE);
+
+    match client
+        .download_input(day, &path)
+        .map_err(|error| error.to_string())?
+    {
+        Download::Fetched => println!("Downloaded {}", path.display()),
+        Download::Cached => println!("{} is already downloaded", path.display()),
+    }
+
+    Ok(true)
+}
+
+fn submit(args: &[String]) -> Result<bool, String> {
+    let day = parse_day(args.first())?;
+    let part = args.get(1).ok_or("Missing part")?;
+
+    let answer = match args.get(2) {
+        Some(answer) => answer.clone(),
+        None => solve_part(day, part)?,
+    };
+
+    let client = Client::from_env().map_err(|error| error.to_string())?;
+    let submission = submit_answer(&client, day_dir(day), day, part, &answer, SystemTime::now())
+        .map_err(|error| error.to_string())?;
+
+    println!("day{} {}: {} -> {}", day, part, answer, submission.outcome);
+    println!("{}", submission.message);
+
+    Ok(submission.outcome == Outcome::Correct)
+}
+
+fn solve_part(number: u8, part: &str) -> Result<String, String> {
+    let entry = days::all()
+        .into_iter()
+        .find(|entry| entry.number == number)
+        .ok_or_else(|| format!("day{} is not registered", number))?;
+
+    let input = entry.input(&[]).map_err(|error| error.to_string())?;
+    let mut day = entry
+        .day()
+        .map_err(|error| format!("{}: {}", entry.name, error))?
+        .quiet();
+    (entry.solve)(&mut day, &input);
+
+    let report = day
+        .reports()
+        .iter()
+        .find(|report| report.part == part)
+        .ok_or_else(|| format!("day{} has no {}", number, part))?;
+
+    submittable_answer(report)
+}
+
+// A failed part would still count as an attempt on the server, so only real answers are sent.
+fn submittable_answer(report: &RunReport) -> Result<String, String> {
+    if report.is_failure() {
+        return Err(format!("Not submitting {}", report));
+    }
+    if report.answer.is_empty() {
+        return Err(format!("{} produced no answer", report.name()));
+    }
+
+    Ok(report.answer.clone())
+}
+
+fn parse_day(arg: Option<&String>) -> Result<u8, String> {
+    let arg = arg.ok_or("Missing day")?;
+
+    arg.parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| format!("Invalid day `{}`", arg))
+}
+
+fn day_dir(day: u8) -> PathBuf {
+    root().join(format!("day{}", day))
+}
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use helpers::Status;
+
+    use super::*;
+
+    #[test]
+    fn test_submittable_answer() {
+        let mut report = RunReport::new(Some(3), "part1", "42".to_string(), Duration::ZERO);
+        assert_eq!(submittable_answer(&report), Ok("42".to_string()));
+
+        report.answer = String::new();
+        assert_eq!(
+            submittable_answer(&report),
+            Err("day3 part1 produced no answer".to_string())
+        );
+
+        report.status = Status::Panicked {
+            message: "boom".to_string(),
+            location: Some("src/lib.rs:7:5".to_string()),
+        };
+        assert_eq!(
+            submittable_answer(&report),
+            Err(
+                "Not submitting day3 part1: panicked at src/lib.rs:7:5: boom (0.00ns) ✗"
+                    .to_string()
+            )
+        );
+
+        report.status = Status::TimedOut {
+            limit: Duration::from_secs(1),
+        };
+        assert!(submittable_answer(&report).unwrap_err().contains("TIMEOUT"));
+    }
+}
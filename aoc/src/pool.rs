@@ -0,0 +1,67 @@
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Maps `items` on up to `jobs` threads, returning the results in the order of `items`.
+pub fn map<T, R, F>(items: &[T], jobs: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    if jobs <= 1 || items.len() <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let results: Vec<Mutex<Option<R>>> = items.iter().map(|_| Mutex::new(None)).collect();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                *results[index].lock().unwrap() = Some(f(item));
+            });
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|result| result.into_inner().unwrap().unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_map_keeps_order() {
+        let items: Vec<u64> = (1..=8).collect();
+
+        let results = map(&items, 4, |&item| {
+            // Later items finish first.
+            thread::sleep(Duration::from_millis(10 * (8 - item)));
+            item * item
+        });
+
+        assert_eq!(results, [1, 4, 9, 16, 25, 36, 49, 64]);
+    }
+
+    #[test]
+    fn test_map_single_job() {
+        let items = ["a", "bb", "ccc"];
+
+        assert_eq!(map(&items, 1, |item| item.len()), [1, 2, 3]);
+    }
+}
@@ -0,0 +1,384 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE_DIR: &str = "_template";
+const TEMPLATE_NAME: &str = "dayN";
+const TEMPLATE_SOLUTION: &str = "DayN";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Kind {
+    Lines,
+    Grid,
+}
+
+pub fn create_day(root: &Path, number: u8, kind: Kind) -> Result<PathBuf, String> {
+    let name = format!("day{}", number);
+    let dir = root.join(&name);
+
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let template = root.join(TEMPLATE_DIR);
+    copy_dir(&template, &dir)
+        .map_err(|error| format!("Could not copy {}: {}", template.display(), error))?;
+
+    let result = customize(&dir, &name, number, kind).and_then(|_| register(root, number, &name));
+    if result.is_err() {
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    result.map(|_| dir)
+}
+
+fn copy_dir(from: &Path, to: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(to)?;
+
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            if entry.file_name() != "target" {
+                copy_dir(&entry.path(), &target)?;
+            }
+        } else if entry.file_name() != "Cargo.lock" {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn customize(dir: &Path, name: &str, number: u8, kind: Kind) -> Result<(), String> {
+    let solution = format!("Day{}", number);
+
+    edit(&dir.join("Cargo.toml"), |content| {
+        replace(
+            content,
+            &format!("name = \"{}\"", TEMPLATE_NAME),
+            &format!("name = \"{}\"", name),
+        )
+    })?;
+
+    edit(&dir.join("src/main.rs"), |content| {
+        replace(
+            content,
+            &format!("use {}::{};", TEMPLATE_NAME, TEMPLATE_SOLUTION),
+            &format!("use {}::{};", name, solution),
+        )
+        .map(|content| content.replace(TEMPLATE_SOLUTION, &solution))
+    })?;
+
+    edit(&dir.join("src/lib.rs"), |content| {
+        let content = content.replace(TEMPLATE_SOLUTION, &solution);
+
+        match kind {
+            Kind::Lines => Ok(content),
+            Kind::Grid => replace(
+                &content,
+                "use helpers::{input_lines, Solution};",
+                "use helpers::{input_grid, Grid, Solution};",
+            )
+            .and_then(|content| {
+                replace(
+                    &content,
+                    "type Input<'a> = Vec<&'a str>;",
+                    "type Input<'a> = Grid<char>;",
+                )
+            })
+            .and_then(|content| replace(&content, "input_lines(input)", "input_grid(input)"))
+            .and_then(|content| {
+                replace(
+                    &content,
+                    "fn input<'a>() -> Vec<&'a str> {",
+                    "fn input() -> Grid<char> {",
+                )
+            }),
+        }
+    })
+}
+
+// Every edit is prepared before any file is written, so a failing one leaves the workspace intact.
+fn register(root: &Path, number: u8, name: &str) -> Result<(), String> {
+    let mut edits = vec![];
+
+    edits.push(edited(&root.join("aoc/Cargo.toml"), |content| {
+        insert_ordered(
+            content,
+            |line| {
+                line.strip_prefix("day")?
+                    .split_once(" = { path")?
+                    .0
+                    .parse()
+                    .ok()
+            },
+            None,
+            number,
+            &format!("{} = {{ path = \"../{}\" }}", name, name),
+        )
+    })?);
+
+    edits.push(edited(&root.join("aoc/src/days.rs"), |content| {
+        insert_ordered(
+            content,
+            |line| {
+                line.trim_start()
+                    .strip_prefix("entry!(")?
+                    .split_once(',')?
+                    .0
+                    .parse()
+                    .ok()
+            },
+            Some("    ]"),
+            number,
+            &format!(
+                "        entry!({}, {}, solution!({}::Day{})),",
+                number, name, name, number
+            ),
+        )
+    })?);
+
+    let workflow = root.join(".github/workflows/rust.yml");
+    if workflow.exists() {
+        edits.push(edited(&workflow, |content| {
+            let start = content
+                .find("day: [ ")
+                .ok_or("Could not find the days matrix")?
+                + "day: [ ".len();
+            let end = start
+                + content[start..]
+                    .find(" ]")
+                    .ok_or("Could not find the days matrix")?;
+
+            let mut days: Vec<u8> = content[start..end]
+                .split(',')
+                .map(|day| {
+                    day.trim()
+                        .parse()
+                        .map_err(|_| format!("Invalid day `{}`", day))
+                })
+                .collect::<Result<_, _>>()?;
+            days.push(number);
+            days.sort();
+
+            let days: Vec<String> = days.iter().map(|day| day.to_string()).collect();
+
+            Ok(format!(
+                "{}{}{}",
+                &content[..start],
+                days.join(", "),
+                &content[end..]
+            ))
+        })?);
+    }
+
+    for (path, content) in edits {
+        fs::write(&path, content).map_err(|error| format!("{}: {}", path.display(), error))?;
+    }
+
+    Ok(())
+}
+
+fn edit<F>(path: &Path, change: F) -> Result<(), String>
+where
+    F: FnOnce(&str) -> Result<String, String>,
+{
+    let (path, content) = edited(path, change)?;
+
+    fs::write(&path, content).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+// Reads `path` and applies `change`, returning the new content without writing it.
+fn edited<F>(path: &Path, change: F) -> Result<(PathBuf, String), String>
+where
+    F: FnOnce(&str) -> Result<String, String>,
+{
+    let content =
+        fs::read_to_string(path).map_err(|error| format!("{}: {}", path.display(), error))?;
+    let content = change(&content).map_err(|error| format!("{}: {}", path.display(), error))?;
+
+    Ok((path.to_path_buf(), content))
+}
+
+fn replace(content: &str, from: &str, to: &str) -> Result<String, String> {
+    if content.contains(from) {
+        Ok(content.replace(from, to))
+    } else {
+        Err(format!("Could not find `{}`", from))
+    }
+}
+
+// Inserts `line` next to the lines `day_of` recognizes as days, keeping them in calendar order.
+// Entries can span several lines, so `end` marks where the list closes when appending.
+fn insert_ordered<F>(
+    content: &str,
+    day_of: F,
+    end: Option<&str>,
+    number: u8,
+    line: &str,
+) -> Result<String, String>
+where
+    F: Fn(&str) -> Option<u8>,
+{
+    let mut lines: Vec<&str> = content.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, day_of(line)?)))
+        .collect();
+
+    let last = match end {
+        Some(end) => lines.iter().rposition(|line| *line == end),
+        None => days.last().map(|(index, _)| index + 1),
+    };
+    let index = days
+        .iter()
+        .find(|(_, day)| *day > number)
+        .map(|(index, _)| *index)
+        .or(last)
+        .ok_or_else(|| format!("Could not find where to add `{}`", line))?;
+
+    lines.insert(index, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    fn fake_root(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        copy_dir(&repo.join(TEMPLATE_DIR), &root.join(TEMPLATE_DIR)).unwrap();
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::create_dir_all(root.join(".github/workflows")).unwrap();
+        fs::create_dir_all(root.join("day1")).unwrap();
+
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\nhelpers = { path = \"../helpers\" }\nday1 = { path = \"../day1\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/days.rs"),
+            "pub fn all() -> Vec<Entry> {\n    vec![\n        entry!(1, day1, |day: &mut Day, input: &str| {\n            day.run(\"part1\", || 1);\n        }),\n    ]\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join(".github/workflows/rust.yml"),
+            "    strategy:\n      matrix:\n        day: [ 1 ]\n",
+        )
+        .unwrap();
+
+        root
+    }
+
+    #[test]
+    fn test_create_lines_day() {
+        let root = fake_root("lines");
+
+        let dir = create_day(&root, 12, Kind::Lines).unwrap();
+
+        assert_eq!(dir, root.join("day12"));
+        assert!(dir.join("input.txt").exists());
+        assert!(fs::read_to_string(dir.join("Cargo.toml"))
+            .unwrap()
+            .contains("name = \"day12\""));
+
+        let main = fs::read_to_string(dir.join("src/main.rs")).unwrap();
+        assert!(main.contains("use day12::Day12;"));
+        assert!(main.contains("day.solve::<Day12>(&input);"));
+
+        assert_eq!(
+            fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap(),
+            "[dependencies]\nhelpers = { path = \"../helpers\" }\nday1 = { path = \"../day1\" }\nday12 = { path = \"../day12\" }\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("aoc/src/days.rs")).unwrap(),
+            "pub fn all() -> Vec<Entry> {\n    vec![\n        entry!(1, day1, |day: &mut Day, input: &str| {\n            day.run(\"part1\", || 1);\n        }),\n        entry!(12, day12, solution!(day12::Day12)),\n    ]\n}\n"
+        );
+        assert!(fs::read_to_string(root.join(".github/workflows/rust.yml"))
+            .unwrap()
+            .contains("day: [ 1, 12 ]"));
+    }
+
+    #[test]
+    fn test_create_grid_day() {
+        let root = fake_root("grid");
+
+        let dir = create_day(&root, 3, Kind::Grid).unwrap();
+
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.starts_with("use helpers::{input_grid, Grid, Solution};\n\npub struct Day3;"));
+        assert!(lib.contains("type Input<'a> = Grid<char>;"));
+        assert!(lib.contains("input_grid(input)"));
+        assert!(lib.contains("fn input() -> Grid<char> {"));
+
+        assert!(fs::read_to_string(root.join("aoc/src/days.rs"))
+            .unwrap()
+            .contains("entry!(3, day3, solution!(day3::Day3)),"));
+    }
+
+    #[test]
+    fn test_registers_in_calendar_order() {
+        let root = fake_root("order");
+
+        create_day(&root, 9, Kind::Lines).unwrap();
+        create_day(&root, 4, Kind::Lines).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap(),
+            "[dependencies]\nhelpers = { path = \"../helpers\" }\nday1 = { path = \"../day1\" }\nday4 = { path = \"../day4\" }\nday9 = { path = \"../day9\" }\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("aoc/src/days.rs")).unwrap(),
+            "pub fn all() -> Vec<Entry> {\n    vec![\n        entry!(1, day1, |day: &mut Day, input: &str| {\n            day.run(\"part1\", || 1);\n        }),\n        entry!(4, day4, solution!(day4::Day4)),\n        entry!(9, day9, solution!(day9::Day9)),\n    ]\n}\n"
+        );
+        assert!(fs::read_to_string(root.join(".github/workflows/rust.yml"))
+            .unwrap()
+            .contains("day: [ 1, 4, 9 ]"));
+    }
+
+    #[test]
+    fn test_refuses_existing_day() {
+        let root = fake_root("existing");
+
+        assert_eq!(
+            create_day(&root, 1, Kind::Lines),
+            Err(format!("{} already exists", root.join("day1").display()))
+        );
+    }
+
+    #[test]
+    fn test_failed_registration_changes_nothing() {
+        let root = fake_root("failed");
+        let workflow = root.join(".github/workflows/rust.yml");
+        fs::write(&workflow, "    strategy:\n      matrix:\n        days: 1\n").unwrap();
+
+        let cargo = fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+        let days = fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+
+        assert_eq!(
+            create_day(&root, 5, Kind::Lines),
+            Err(format!(
+                "{}: Could not find the days matrix",
+                workflow.display()
+            ))
+        );
+        assert!(!root.join("day5").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap(),
+            cargo
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("aoc/src/days.rs")).unwrap(),
+            days
+        );
+    }
+}
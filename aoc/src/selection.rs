@@ -0,0 +1,80 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selection {
+    All,
+    Days(Vec<RangeInclusive<u8>>),
+}
+
+impl Selection {
+    pub fn contains(&self, day: u8) -> bool {
+        match self {
+            Selection::All => true,
+            Selection::Days(ranges) => ranges.iter().any(|range| range.contains(&day)),
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Selection::All);
+        }
+
+        s.split(',')
+            .map(|part| {
+                parse_range(part.trim()).ok_or_else(|| format!("Invalid day selection `{}`", part))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Selection::Days)
+    }
+}
+
+fn parse_range(value: &str) -> Option<RangeInclusive<u8>> {
+    let range = if let Some((start, end)) = value.split_once("..=") {
+        start.parse().ok()?..=end.parse().ok()?
+    } else if let Some((start, end)) = value.split_once("..") {
+        start.parse().ok()?..=end.parse::<u8>().ok()?.checked_sub(1)?
+    } else {
+        let day = value.parse().ok()?;
+        day..=day
+    };
+
+    (!range.is_empty() && *range.start() > 0).then_some(range)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("all".parse(), Ok(Selection::All));
+        assert_eq!("7".parse(), Ok(Selection::Days(vec![7..=7])));
+        assert_eq!("3..=9".parse(), Ok(Selection::Days(vec![3..=9])));
+        assert_eq!("3..9".parse(), Ok(Selection::Days(vec![3..=8])));
+        assert_eq!("1,4..=5".parse(), Ok(Selection::Days(vec![1..=1, 4..=5])));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!("".parse::<Selection>().is_err());
+        assert!("0".parse::<Selection>().is_err());
+        assert!("9..=3".parse::<Selection>().is_err());
+        assert!("3..3".parse::<Selection>().is_err());
+        assert!("first".parse::<Selection>().is_err());
+    }
+
+    #[test]
+    fn test_contains() {
+        let selection: Selection = "1,4..=5".parse().unwrap();
+
+        assert!(selection.contains(1));
+        assert!(!selection.contains(3));
+        assert!(selection.contains(5));
+        assert!(Selection::All.contains(25));
+    }
+}
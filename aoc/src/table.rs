@@ -0,0 +1,245 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::time::Duration;
+
+use helpers::{RunReport, Status};
+
+pub fn render(reports: &[RunReport], wall_time: Duration) -> String {
+    // Allocation stats only exist when the runner is built with `count-alloc`.
+    let memory = reports.iter().any(|report| report.alloc.is_some());
+
+    let rows: Vec<Vec<String>> = reports
+        .iter()
+        .map(|report| {
+            let mut row = vec![
+                report.day.map_or("-".to_string(), |day| day.to_string()),
+                report.part.clone(),
+                report.answer.clone(),
+                report
+                    .parse_time
+                    .map_or(String::new(), |time| format!("{:.2?}", time)),
+                format!("{:.2?}", report.solve_time),
+            ];
+            if memory {
+                row.push(
+                    report
+                        .alloc
+                        .map_or(String::new(), |alloc| alloc.to_string()),
+                );
+            }
+            row.push(match &report.status {
+                Status::Unknown => String::new(),
+                Status::Correct => "✓".to_string(),
+                Status::Wrong { expected } => format!("✗ expected {}", expected),
+                Status::Panicked {
+                    message,
+                    location: Some(location),
+                } => format!("✗ panicked at {}: {}", location, message),
+                Status::Panicked { message, .. } => format!("✗ panicked: {}", message),
+                Status::TimedOut { .. } => "✗ TIMEOUT".to_string(),
+            });
+            row
+        })
+        .collect();
+
+    // Both parts of a day share one parse, so it only counts once towards the total.
+    let mut parsed_days = HashSet::new();
+    let parse_total: Duration = reports
+        .iter()
+        .filter(|report| parsed_days.insert(report.day))
+        .filter_map(|report| report.parse_time)
+        .sum();
+    let solve_total: Duration = reports.iter().map(|report| report.solve_time).sum();
+
+    let mut header = vec!["Day", "Part", "Answer", "Parse", "Solve"];
+    if memory {
+        header.push("Memory");
+    }
+    header.push("");
+    let header: Vec<String> = header.iter().map(|title| title.to_string()).collect();
+
+    let totals = [
+        format!("{:.2?}", parse_total),
+        format!("{:.2?}", solve_total),
+        format!("{:.2?}", wall_time),
+    ];
+
+    let mut widths = vec![0; header.len()];
+    for row in rows.iter().chain([&header]) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    widths[3] = widths[3].max(totals[0].chars().count());
+    widths[4] = widths[4]
+        .max(totals[1].chars().count())
+        .max(totals[2].chars().count());
+
+    let mut output = String::new();
+    write_row(&mut output, &widths, &header);
+    for (row, report) in rows.iter().zip(reports) {
+        write_row(&mut output, &widths, row);
+
+        // Span trees (with the `profile` feature) go beneath their row, starting at the Part column.
+        if let Some(profile) = &report.profile {
+            for line in profile.to_string().lines() {
+                writeln!(output, "{:width$}{}", "", line, width = widths[0]).unwrap();
+            }
+        }
+    }
+
+    // Parts run in parallel, so the sum of their times can exceed the wall clock time.
+    let label_width = widths[0] + widths[1] + widths[2] + 4;
+    for (label, parse, solve) in [
+        ("Sum of parts", totals[0].as_str(), totals[1].as_str()),
+        ("Wall time", "", totals[2].as_str()),
+    ] {
+        writeln!(
+            output,
+            "{:<label_width$}  {:>parse_width$}  {:>solve_width$}",
+            label,
+            parse,
+            solve,
+            parse_width = widths[3],
+            solve_width = widths[4]
+        )
+        .unwrap();
+    }
+
+    output
+}
+
+fn write_row(output: &mut String, widths: &[usize], row: &[String]) {
+    let mut line = String::new();
+
+    for (index, (cell, width)) in row.iter().zip(widths).enumerate() {
+        if index > 0 {
+            line.push_str("  ");
+        }
+        match index {
+            // Day and timings are right aligned, the rest left aligned.
+            0 | 3 | 4 => write!(line, "{:>width$}", cell).unwrap(),
+            _ => write!(line, "{:<width$}", cell).unwrap(),
+        }
+    }
+
+    writeln!(output, "{}", line.trim_end()).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use helpers::{AllocStats, Profile, SpanStats};
+
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let mut first = RunReport::new(
+            Some(1),
+            "part1",
+            "55386".to_string(),
+            Duration::from_millis(2),
+        );
+        first.parse_time = Some(Duration::from_millis(1));
+        first.status = Status::Correct;
+        let mut shared = first.clone();
+        shared.part = "part2".to_string();
+        shared.answer = "54824".to_string();
+        let mut second = RunReport::new(
+            Some(10),
+            "part2",
+            "0".to_string(),
+            Duration::from_micros(500),
+        );
+        second.status = Status::Wrong {
+            expected: "7".to_string(),
+        };
+
+        assert_eq!(
+            render(&[first, shared, second], Duration::from_millis(3)),
+            "\
+Day  Part   Answer   Parse     Solve
+  1  part1  55386   1.00ms    2.00ms  ✓
+  1  part2  54824   1.00ms    2.00ms  ✓
+ 10  part2  0               500.00µs  ✗ expected 7
+Sum of parts        1.00ms    4.50ms
+Wall time                     3.00ms
+"
+        );
+    }
+
+    #[test]
+    fn test_render_panicked() {
+        let mut report = RunReport::new(Some(2), "part1", String::new(), Duration::from_millis(1));
+        report.status = Status::Panicked {
+            message: "boom".to_string(),
+            location: Some("day2/src/lib.rs:3:5".to_string()),
+        };
+
+        assert_eq!(
+            render(&[report], Duration::from_millis(1)),
+            "\
+Day  Part   Answer   Parse   Solve
+  2  part1                  1.00ms  ✗ panicked at day2/src/lib.rs:3:5: boom
+Sum of parts        0.00ns  1.00ms
+Wall time                   1.00ms
+"
+        );
+    }
+
+    #[test]
+    fn test_render_memory() {
+        let mut report =
+            RunReport::new(Some(5), "part1", "35".to_string(), Duration::from_millis(1));
+        report.alloc = Some(AllocStats {
+            allocations: 12,
+            bytes: 2048,
+            peak: 512,
+        });
+
+        assert_eq!(
+            render(&[report], Duration::from_millis(1)),
+            "\
+Day  Part   Answer   Parse   Solve  Memory
+  5  part1  35              1.00ms  12 allocs, 2.00 KiB, peak 512 B
+Sum of parts        0.00ns  1.00ms
+Wall time                   1.00ms
+"
+        );
+    }
+
+    #[test]
+    fn test_render_profile() {
+        let mut report = RunReport::new(
+            Some(7),
+            "part1",
+            "6440".to_string(),
+            Duration::from_millis(4),
+        );
+        report.profile = Some(Profile {
+            spans: vec![SpanStats {
+                name: "hands",
+                calls: 1,
+                total: Duration::from_millis(3),
+                children: vec![SpanStats {
+                    name: "sort",
+                    calls: 1,
+                    total: Duration::from_millis(2),
+                    children: vec![],
+                }],
+            }],
+        });
+
+        assert_eq!(
+            render(&[report], Duration::from_millis(4)),
+            "\
+Day  Part   Answer   Parse   Solve
+  7  part1  6440            4.00ms
+     hands 3.00ms (1 call)
+       sort 2.00ms (1 call)
+Sum of parts        0.00ns  4.00ms
+Wall time                   4.00ms
+"
+        );
+    }
+}
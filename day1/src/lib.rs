@@ -1,25 +1,36 @@
-pub fn part1(input: &Vec<&str>) -> usize {
-    input.iter().map(|line| find_number(line.to_string())).sum()
-}
+use helpers::{input_lines, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<&'a str>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input_lines(input)
+    }
 
-pub fn part2(input: &Vec<&str>) -> usize {
-    let numbers = [
-        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
-    ];
-
-    input
-        .iter()
-        .map(|line| {
-            find_number(
-                numbers
-                    .iter()
-                    .enumerate()
-                    .fold(line.to_string(), |line, (i, number)| {
+    fn part1(input: &Self::Input<'_>) -> usize {
+        input.iter().map(|line| find_number(line.to_string())).sum()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        let numbers = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+
+        input
+            .iter()
+            .map(|line| {
+                find_number(numbers.iter().enumerate().fold(
+                    line.to_string(),
+                    |line, (i, number)| {
                         line.replace(number, &format!("{}{}{}", number, i + 1, number))
-                    }),
-            )
-        })
-        .sum()
+                    },
+                ))
+            })
+            .sum()
+    }
 }
 
 fn find_number(line: String) -> usize {
@@ -33,8 +44,6 @@ fn find_number(line: String) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use helpers::input_lines;
-
     use super::*;
 
     #[test]
@@ -44,7 +53,7 @@ mod tests {
 pqr3stu8vwx
 a1b2c3d4e5f
 treb7uchet";
-        assert_eq!(part1(&input_lines(input)), 142)
+        assert_eq!(Day1::part1(&Day1::parse(input)), 142)
     }
 
     #[test]
@@ -57,6 +66,6 @@ xtwone3four
 4nineeightseven2
 zoneight234
 7pqrstsixteen";
-        assert_eq!(part2(&input_lines(input)), 281)
+        assert_eq!(Day1::part2(&Day1::parse(input)), 281)
     }
 }
@@ -1,10 +1,11 @@
-use helpers::{Coord, Grid};
+use helpers::{bfs_distances, input_grid, Coord, Direction, Grid, Point, Solution};
 
 fn connections(grid: &Grid<char>, char: char, coord: Coord) -> Vec<Coord> {
-    let top = (coord.0 as isize, coord.1 as isize - 1);
-    let right = (coord.0 as isize + 1, coord.1 as isize);
-    let bottom = (coord.0 as isize, coord.1 as isize + 1);
-    let left = (coord.0 as isize - 1, coord.1 as isize);
+    let point = Point::from(coord);
+    let top = point + Direction::North;
+    let right = point + Direction::East;
+    let bottom = point + Direction::South;
+    let left = point + Direction::West;
 
     match char {
         '|' => {
@@ -33,14 +34,8 @@ fn connections(grid: &Grid<char>, char: char, coord: Coord) -> Vec<Coord> {
         }
     }
     .iter()
-    .filter(|&&coord| {
-        if let Some(char) = grid.maybe_get(coord) {
-            return *char != '.';
-        }
-
-        false
-    })
-    .map(|&coord| (coord.0 as usize, coord.1 as usize))
+    .filter(|&&point| grid.at(point).is_some_and(|&char| char != '.'))
+    .filter_map(|point| point.to_coord())
     .collect()
 }
 
@@ -50,38 +45,40 @@ struct Node {
     connections: Vec<Coord>,
 }
 
-pub fn part1(input: &Grid<char>) -> usize {
-    let start = input.iter().find(|(_, char)| **char == 'S').unwrap();
-    let mut path = vec![start.0];
-    let mut next_connections = connections(input, *start.1, start.0);
+pub struct Day10;
 
-    loop {
-        let next_coord = next_connections
-            .iter()
-            .find(|&&next| path.contains(&next) == false);
+impl Solution for Day10 {
+    type Input<'a> = Grid<char>;
+    type Answer = usize;
 
-        if next_coord.is_none() {
-            break;
-        }
+    fn parse(input: &str) -> Self::Input<'_> {
+        input_grid(input)
+    }
 
-        let next_coord = *next_coord.unwrap();
-        let next_char = input.get(next_coord);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        let (start, _) = input.iter().find(|(_, char)| **char == 'S').unwrap();
+        let connected = |from: Coord, to: Coord| {
+            connections(input, *input.get(from), from).contains(&to)
+                && connections(input, *input.get(to), to).contains(&from)
+        };
 
-        path.push(next_coord);
-        next_connections = connections(input, *next_char, next_coord);
+        // The farthest point of the loop is as many steps away in both directions.
+        bfs_distances(input, start, false, connected)
+            .iter()
+            .filter_map(|(_, distance)| *distance)
+            .max()
+            .unwrap() as usize
     }
 
-    (path.len() as f32 / 2.0).ceil() as usize
-}
-
-pub fn part2(input: &Grid<char>) -> usize {
-    // TODO
-    0
+    fn part2(input: &Self::Input<'_>) -> usize {
+        // TODO
+        0
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use helpers::{input_grid, Grid};
+    use helpers::Grid;
 
     use super::*;
 
@@ -92,7 +89,7 @@ mod tests {
 SJ.L7
 |F--J
 LJ...";
-        input_grid(input)
+        Day10::parse(input)
     }
 
     #[test]
@@ -104,7 +101,7 @@ SJ.L7
 |F--J
 LJ...";
 
-        assert_eq!(part1(&input_grid(input)), 8)
+        assert_eq!(Day10::part1(&Day10::parse(input)), 8)
     }
 
     #[test]
@@ -122,6 +119,6 @@ L.L7LFJ|||||FJL7||LJ
 L7JLJL-JLJLJL--JLJ.L";
 
         // TODO
-        // assert_eq!(part2(&input_grid(input)), 10)
+        // assert_eq!(Day10::part2(&Day10::parse(input)), 10)
     }
 }
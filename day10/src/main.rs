@@ -1,8 +1,11 @@
-use helpers::{input_grid, run, Grid};
+use day10::Day10;
+use helpers::{day, input};
 
 fn main() {
-    let input: Grid<char> = input_grid(include_str!("../input.txt"));
+    let input = input!();
+    let mut day = day!();
 
-    run("part1", || day10::part1(&input));
-    run("part2", || day10::part2(&input));
+    day.solve::<Day10>(&input);
+
+    day.finish();
 }
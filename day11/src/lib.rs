@@ -1,15 +1,26 @@
-use helpers::{Coord, Grid};
+use helpers::{input_grid, Coord, Grid, Point, Solution};
 
-pub fn part1(input: &Grid<char>) -> usize {
-    calc_expanded(input, 1)
-}
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Grid<char>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input_grid(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        calc_expanded(input, 1)
+    }
 
-pub fn part2(input: &Grid<char>, expand: usize) -> usize {
-    calc_expanded(input, expand)
+    fn part2(input: &Self::Input<'_>) -> usize {
+        calc_expanded(input, 1000000 - 1)
+    }
 }
 
 fn distance(a: &Coord, b: &Coord) -> usize {
-    ((a.0 as isize - b.0 as isize).abs() + (a.1 as isize - b.1 as isize).abs()) as usize
+    Point::from(*a).manhattan(Point::from(*b))
 }
 
 fn calc_expanded(input: &Grid<char>, expand: usize) -> usize {
@@ -19,20 +30,12 @@ fn calc_expanded(input: &Grid<char>, expand: usize) -> usize {
         .map(|(coord, _)| coord)
         .collect();
 
-    let mut expanded_columns: Vec<usize> = vec![];
-    let mut expanded_rows: Vec<usize> = vec![];
-
-    for column in 0..input.size.0 {
-        if !galaxies.iter().any(|coord| coord.0 == column) {
-            expanded_columns.push(column);
-        }
-    }
-
-    for row in 0..input.size.1 {
-        if !galaxies.iter().any(|coord| coord.1 == row) {
-            expanded_rows.push(row);
-        }
-    }
+    let expanded_columns: Vec<usize> = (0..input.size.0)
+        .filter(|&column| input.all_in_col(column, |&char| char != '#'))
+        .collect();
+    let expanded_rows: Vec<usize> = (0..input.size.1)
+        .filter(|&row| input.all_in_row(row, |&char| char != '#'))
+        .collect();
 
     let expanded_galaxies: Vec<Coord> = galaxies
         .iter()
@@ -64,8 +67,6 @@ fn calc_expanded(input: &Grid<char>, expand: usize) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use helpers::input_grid;
-
     use super::*;
 
     fn input<'a>() -> Grid<char> {
@@ -80,17 +81,17 @@ mod tests {
 ..........
 .......#..
 #...#.....";
-        input_grid(input)
+        Day11::parse(input)
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(&input()), 374)
+        assert_eq!(Day11::part1(&input()), 374)
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(&input(), 10 - 1), 1030);
-        assert_eq!(part2(&input(), 100 - 1), 8410);
+        assert_eq!(calc_expanded(&input(), 10 - 1), 1030);
+        assert_eq!(calc_expanded(&input(), 100 - 1), 8410);
     }
 }
@@ -1,8 +1,11 @@
-use helpers::{input_grid, run, Grid};
+use day11::Day11;
+use helpers::{day, input};
 
 fn main() {
-    let input: Grid<char> = input_grid(include_str!("../input.txt"));
+    let input = input!();
+    let mut day = day!();
 
-    run("part1", || day11::part1(&input));
-    run("part2", || day11::part2(&input, 1000000 - 1));
+    day.solve::<Day11>(&input);
+
+    day.finish();
 }
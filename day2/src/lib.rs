@@ -1,79 +1,88 @@
-use std::collections::HashMap;
+use helpers::{parse_records, PuzzleParse, Solution};
 
-pub fn part1(input: &Vec<&str>) -> usize {
-    let cubes = HashMap::from([("red", 12usize), ("green", 13usize), ("blue", 14usize)]);
-
-    input
-        .iter()
-        .filter_map(|line| {
-            let (id, game) = line.split_once(": ").unwrap();
-
-            let game_id = id[5..].parse::<usize>().unwrap();
-
-            for sets in game.split("; ") {
-                for set in sets.split(", ") {
-                    let (count, color) = set.split_once(' ').unwrap();
-
-                    if count.parse::<usize>().unwrap() > *cubes.get(color).unwrap() {
-                        return None;
-                    }
-                }
-            }
+#[derive(Debug, Clone, Copy, PartialEq, PuzzleParse)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
 
-            Some(game_id)
-        })
-        .sum()
+#[derive(Debug, PuzzleParse)]
+pub struct Game {
+    #[puzzle(prefix = "Game")]
+    id: usize,
+    #[puzzle(prefix = ":", sep = ["; ", ", ", " "])]
+    sets: Vec<Vec<(usize, Color)>>,
 }
 
-pub fn part2(input: &Vec<&str>) -> usize {
-    input
-        .iter()
-        .filter_map(|line| {
-            let (id, game) = line.split_once(": ").unwrap();
+impl Game {
+    fn max_count(&self, color: Color) -> usize {
+        self.sets
+            .iter()
+            .flatten()
+            .filter(|(_, cube)| *cube == color)
+            .map(|(count, _)| *count)
+            .max()
+            .unwrap_or(0)
+    }
+}
 
-            let game_id = id[5..].parse::<usize>().unwrap();
+pub struct Day2;
 
-            let mut colors = HashMap::new();
+impl Solution for Day2 {
+    type Input<'a> = Vec<Game>;
+    type Answer = usize;
 
-            for sets in game.split("; ") {
-                for set in sets.split(", ") {
-                    let (count, color) = set.split_once(' ').unwrap();
-                    let count = count.parse::<usize>().unwrap();
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_records(input)
+    }
 
-                    if !colors.contains_key(color) || count > *colors.get(color).unwrap() {
-                        colors.insert(color, count);
-                    }
-                }
-            }
+    fn part1(input: &Self::Input<'_>) -> usize {
+        input
+            .iter()
+            .filter(|game| {
+                game.max_count(Color::Red) <= 12
+                    && game.max_count(Color::Green) <= 13
+                    && game.max_count(Color::Blue) <= 14
+            })
+            .map(|game| game.id)
+            .sum()
+    }
 
-            Some(colors.values().product::<usize>())
-        })
-        .sum()
+    fn part2(input: &Self::Input<'_>) -> usize {
+        input
+            .iter()
+            .map(|game| {
+                [Color::Red, Color::Green, Color::Blue]
+                    .iter()
+                    .map(|&color| game.max_count(color))
+                    .product::<usize>()
+            })
+            .sum()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use helpers::input_lines;
-
     use super::*;
 
-    fn input<'a>() -> Vec<&'a str> {
+    fn input() -> Vec<Game> {
         let input = "
 Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
-        input_lines(input)
+        Day2::parse(input)
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(&input()), 8)
+        assert_eq!(Day2::part1(&input()), 8)
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(&input()), 2286)
+        assert_eq!(Day2::part2(&input()), 2286)
     }
 }
@@ -1,8 +1,11 @@
-use helpers::{input_grid, run, Grid};
+use day3::Day3;
+use helpers::{day, input};
 
 fn main() {
-    let input: Grid<char> = input_grid(include_str!("../input.txt"));
+    let input = input!();
+    let mut day = day!();
 
-    run("part1", || day3::part1(&input));
-    run("part2", || day3::part2(&input));
+    day.solve::<Day3>(&input);
+
+    day.finish();
 }
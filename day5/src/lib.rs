@@ -1,154 +1,170 @@
-use std::collections::HashMap;
 use std::ops::Range;
 
-pub fn part1(input: &Vec<&str>) -> u64 {
-    let mut next_ids = input[0]
-        .split_once(": ")
-        .unwrap()
-        .1
-        .split_whitespace()
-        .map(|s| s.parse::<u64>().unwrap())
-        .enumerate()
-        .collect::<HashMap<usize, u64>>();
-
-    let mut ids = HashMap::new();
-
-    for line in input[1..].iter() {
-        if line.is_empty() {
-            continue;
-        }
-
-        if line.contains(':') {
-            for (&i, &id) in ids.iter() {
-                next_ids.entry(i).or_insert(id);
-            }
-
-            ids = next_ids.clone();
-            next_ids = HashMap::new();
-
-            continue;
-        }
-
-        let numbers = line
-            .split_whitespace()
-            .map(|s| s.parse::<u64>().unwrap())
-            .collect::<Vec<u64>>();
+use helpers::{span, Solution};
 
-        let source = numbers[1]..numbers[1] + numbers[2];
-        let destination = numbers[0]..numbers[0] + numbers[2];
-
-        for (i, &id) in ids.iter() {
-            if next_ids.contains_key(i) {
-                continue;
-            }
+/// Maps `source..source + len` onto `destination..destination + len`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mapping {
+    destination: u64,
+    source: u64,
+    len: u64,
+}
 
-            if id >= source.start && id < source.end {
-                let new_id = id - source.start + destination.start;
+impl Mapping {
+    fn source(&self) -> Range<u64> {
+        self.source..self.source + self.len
+    }
 
-                next_ids.insert(*i, new_id);
-            }
-        }
+    fn destination(&self) -> Range<u64> {
+        self.destination..self.destination + self.len
     }
 
-    for (&i, &id) in ids.iter() {
-        next_ids.entry(i).or_insert(id);
+    fn translate(&self, id: u64) -> Option<u64> {
+        self.source()
+            .contains(&id)
+            .then(|| id - self.source + self.destination)
     }
+}
 
-    *next_ids.values().min().unwrap()
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Vec<Mapping>>,
 }
 
-pub fn part2(input: &Vec<&str>) -> u64 {
-    let seeds = input[0]
-        .split_once(": ")
-        .unwrap()
-        .1
-        .split_whitespace()
-        .map(|s| s.parse::<u64>().unwrap())
-        .collect::<Vec<u64>>();
-
-    let seed_ranges = seeds
-        .chunks(2)
-        .map(|chunk| chunk[0]..chunk[0] + chunk[1])
-        .collect::<Vec<Range<u64>>>();
-
-    let mut ranges = seed_ranges.clone();
-    let mut next_ranges = Vec::new();
-
-    for line in input[1..].iter() {
-        if line.is_empty() {
-            continue;
-        }
+pub struct Day5;
 
-        if line.contains(':') {
-            ranges.append(&mut next_ranges);
-            continue;
-        }
+impl Solution for Day5 {
+    type Input<'a> = Almanac;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let _s = span("almanac");
+        let mut sections = input.trim().split("\n\n");
 
-        let numbers = line
+        let seeds = sections
+            .next()
+            .unwrap()
+            .split_once(": ")
+            .unwrap()
+            .1
             .split_whitespace()
             .map(|s| s.parse::<u64>().unwrap())
-            .collect::<Vec<u64>>();
-
-        let source = numbers[1]..numbers[1] + numbers[2];
-        let destination = numbers[0]..numbers[0] + numbers[2];
-        let transpose = |number: u64| number - source.start + destination.start;
-
-        let mut new_ranges = Vec::new();
-
-        for &Range { start, end } in ranges.iter() {
-            if start < source.start {
-                if end <= source.start {
-                    new_ranges.push(start..end);
-                    continue;
-                }
-
-                if end <= source.end {
-                    new_ranges.push(start..source.start);
-                    next_ranges.push(destination.start..transpose(end));
-                    continue;
-                }
+            .collect();
+
+        let maps = sections
+            .map(|section| {
+                section
+                    .lines()
+                    .skip(1)
+                    .map(|line| {
+                        let numbers = line
+                            .split_whitespace()
+                            .map(|s| s.parse::<u64>().unwrap())
+                            .collect::<Vec<u64>>();
+
+                        Mapping {
+                            destination: numbers[0],
+                            source: numbers[1],
+                            len: numbers[2],
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Almanac { seeds, maps }
+    }
 
-                if end > source.end {
-                    new_ranges.push(start..source.start);
-                    next_ranges.push(destination.start..destination.end);
-                    new_ranges.push(source.end..end);
-                    continue;
-                }
-            }
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        input
+            .seeds
+            .iter()
+            .map(|&seed| {
+                input.maps.iter().fold(seed, |id, map| {
+                    map.iter()
+                        .find_map(|mapping| mapping.translate(id))
+                        .unwrap_or(id)
+                })
+            })
+            .min()
+            .unwrap()
+    }
 
-            if start >= source.start && start < source.end {
-                if end <= source.end {
-                    next_ranges.push(transpose(start)..transpose(end));
-                    continue;
+    fn part2(input: &Self::Input<'_>) -> u64 {
+        let mut ranges = input
+            .seeds
+            .chunks(2)
+            .map(|chunk| chunk[0]..chunk[0] + chunk[1])
+            .collect::<Vec<Range<u64>>>();
+
+        for map in &input.maps {
+            let mut next_ranges = Vec::new();
+
+            for mapping in map {
+                let _s = span("map ranges");
+
+                let source = mapping.source();
+                let destination = mapping.destination();
+                let transpose = |number: u64| number - source.start + destination.start;
+
+                let mut new_ranges = Vec::new();
+
+                for &Range { start, end } in ranges.iter() {
+                    if start < source.start {
+                        if end <= source.start {
+                            new_ranges.push(start..end);
+                            continue;
+                        }
+
+                        if end <= source.end {
+                            new_ranges.push(start..source.start);
+                            next_ranges.push(destination.start..transpose(end));
+                            continue;
+                        }
+
+                        if end > source.end {
+                            new_ranges.push(start..source.start);
+                            next_ranges.push(destination.start..destination.end);
+                            new_ranges.push(source.end..end);
+                            continue;
+                        }
+                    }
+
+                    if start >= source.start && start < source.end {
+                        if end <= source.end {
+                            next_ranges.push(transpose(start)..transpose(end));
+                            continue;
+                        }
+
+                        if end > source.end {
+                            next_ranges.push(transpose(start)..destination.end);
+                            new_ranges.push(source.end..end);
+                            continue;
+                        }
+                    }
+
+                    if start >= source.end {
+                        new_ranges.push(start..end);
+                        continue;
+                    }
                 }
 
-                if end > source.end {
-                    next_ranges.push(transpose(start)..destination.end);
-                    new_ranges.push(source.end..end);
-                    continue;
-                }
+                ranges = new_ranges;
             }
 
-            if start >= source.end {
-                new_ranges.push(start..end);
-                continue;
-            }
+            ranges.append(&mut next_ranges);
         }
 
-        ranges = new_ranges.clone();
+        ranges.iter().map(|range| range.start).min().unwrap()
     }
-
-    ranges.append(&mut next_ranges);
-    ranges.iter().map(|range| range.start).min().unwrap()
 }
 
 #[cfg(test)]
 mod tests {
-    use helpers::input_lines;
-
     use super::*;
 
-    fn input<'a>() -> Vec<&'a str> {
+    fn input() -> Almanac {
         let input = "
 seeds: 79 14 55 13
 
@@ -183,16 +199,16 @@ temperature-to-humidity map:
 humidity-to-location map:
 60 56 37
 56 93 4";
-        input_lines(input)
+        Day5::parse(input)
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(&input()), 35)
+        assert_eq!(Day5::part1(&input()), 35)
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(&input()), 46)
+        assert_eq!(Day5::part2(&input()), 46)
     }
 }
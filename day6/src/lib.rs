@@ -1,49 +1,55 @@
-pub fn part1(input: &Vec<&str>) -> u64 {
-    let times = input
-        .get(0)
-        .unwrap()
-        .split_whitespace()
-        .skip(1)
-        .map(|s| s.parse::<u64>().unwrap())
-        .collect::<Vec<u64>>();
+use helpers::{input_lines, Solution};
 
-    let distances = input
-        .get(1)
-        .unwrap()
-        .split_whitespace()
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Races {
+    times: Vec<u64>,
+    distances: Vec<u64>,
+}
+
+fn numbers(line: &str) -> Vec<u64> {
+    line.split_whitespace()
         .skip(1)
         .map(|s| s.parse::<u64>().unwrap())
-        .collect::<Vec<u64>>();
+        .collect()
+}
 
-    times
+// Part 2 reads a line's numbers as a single number, ignoring the spaces between them.
+fn joined(numbers: &[u64]) -> u64 {
+    numbers
         .iter()
-        .zip(distances.iter())
-        .map(|(&time, &distance)| calc_record_beating_ways(time, distance))
-        .product()
+        .map(|number| number.to_string())
+        .collect::<String>()
+        .parse()
+        .unwrap()
 }
 
-pub fn part2(input: &Vec<&str>) -> u64 {
-    let time = input
-        .get(0)
-        .unwrap()
-        .split_once(": ")
-        .unwrap()
-        .1
-        .replace(" ", "")
-        .parse::<u64>()
-        .unwrap();
+pub struct Day6;
 
-    let distance = input
-        .get(1)
-        .unwrap()
-        .split_once(": ")
-        .unwrap()
-        .1
-        .replace(" ", "")
-        .parse::<u64>()
-        .unwrap();
+impl Solution for Day6 {
+    type Input<'a> = Races;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let lines: Vec<&str> = input_lines(input);
+
+        Races {
+            times: numbers(lines[0]),
+            distances: numbers(lines[1]),
+        }
+    }
 
-    calc_record_beating_ways(time, distance)
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        input
+            .times
+            .iter()
+            .zip(input.distances.iter())
+            .map(|(&time, &distance)| calc_record_beating_ways(time, distance))
+            .product()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> u64 {
+        calc_record_beating_ways(joined(&input.times), joined(&input.distances))
+    }
 }
 
 fn calc_record_beating_ways(time: u64, distance: u64) -> u64 {
@@ -56,24 +62,22 @@ fn calc_record_beating_ways(time: u64, distance: u64) -> u64 {
 
 #[cfg(test)]
 mod tests {
-    use helpers::input_lines;
-
     use super::*;
 
-    fn input<'a>() -> Vec<&'a str> {
+    fn input() -> Races {
         let input = "
 Time:      7  15   30
 Distance:  9  40  200";
-        input_lines(input)
+        Day6::parse(input)
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(&input()), 288)
+        assert_eq!(Day6::part1(&input()), 288)
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(&input()), 71503)
+        assert_eq!(Day6::part2(&input()), 71503)
     }
 }
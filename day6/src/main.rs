@@ -1,8 +1,11 @@
-use helpers::{input_lines, run};
+use day6::Day6;
+use helpers::{day, input};
 
 fn main() {
-    let input: Vec<&str> = input_lines(include_str!("../input.txt"));
+    let input = input!();
+    let mut day = day!();
 
-    run("part1", || day6::part1(&input));
-    run("part2", || day6::part2(&input));
+    day.solve::<Day6>(&input);
+
+    day.finish();
 }
@@ -1,6 +1,8 @@
 use std::cmp::Ordering;
 use std::collections::{BTreeSet, HashMap};
 
+use helpers::{input_lines, span, Solution};
+
 const CARD_STRENGTH: [char; 13] = [
     '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
 ];
@@ -210,14 +212,18 @@ impl Ord for Hand {
     }
 }
 
-fn calc_winnings(input: &Vec<&str>, with_joker: bool) -> usize {
-    input
-        .iter()
-        .map(|&line| {
-            let (hand, bid) = line.split_once(' ').unwrap();
-
-            Hand::new(hand.chars().collect(), bid.parse().unwrap(), with_joker)
-        })
+fn calc_winnings(input: &[(Vec<char>, usize)], with_joker: bool) -> usize {
+    let hands = {
+        let _s = span("hands");
+        input
+            .iter()
+            .map(|(cards, bid)| Hand::new(cards.clone(), *bid, with_joker))
+            .collect::<Vec<Hand>>()
+    };
+
+    let _s = span("sort");
+    hands
+        .into_iter()
         .collect::<BTreeSet<Hand>>()
         .iter()
         .enumerate()
@@ -225,28 +231,44 @@ fn calc_winnings(input: &Vec<&str>, with_joker: bool) -> usize {
         .sum()
 }
 
-pub fn part1(input: &Vec<&str>) -> usize {
-    calc_winnings(input, false)
-}
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input<'a> = Vec<(Vec<char>, usize)>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input_lines::<Vec<&str>>(input)
+            .iter()
+            .map(|line| {
+                let (cards, bid) = line.split_once(' ').unwrap();
 
-pub fn part2(input: &Vec<&str>) -> usize {
-    calc_winnings(input, true)
+                (cards.chars().collect(), bid.parse().unwrap())
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        calc_winnings(input, false)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        calc_winnings(input, true)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use helpers::input_lines;
-
     use super::*;
 
-    fn input<'a>() -> Vec<&'a str> {
+    fn input() -> Vec<(Vec<char>, usize)> {
         let input = "
 32T3K 765
 T55J5 684
 KK677 28
 KTJJT 220
 QQQJA 483";
-        input_lines(input)
+        Day7::parse(input)
     }
 
     #[test]
@@ -256,11 +278,11 @@ QQQJA 483";
             Some(Ordering::Greater)
         );
 
-        assert_eq!(part1(&input()), 6440)
+        assert_eq!(Day7::part1(&input()), 6440)
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(&input()), 5905)
+        assert_eq!(Day7::part2(&input()), 5905)
     }
 }
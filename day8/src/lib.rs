@@ -1,88 +1,97 @@
 use std::collections::HashMap;
 
-pub fn part1(input: &Vec<&str>) -> usize {
-    let (instructions, nodes) = parse_input(input);
+use helpers::{input_lines, scan_in, Solution};
 
-    let mut current = "AAA";
+pub struct Day8;
 
-    let mut i = 0;
-    while current != "ZZZ" {
-        let instruction = instructions[i % instructions.len()];
+impl Solution for Day8 {
+    type Input<'a> = (Vec<char>, HashMap<&'a str, (&'a str, &'a str)>);
+    type Answer = usize;
 
-        if instruction == 'L' {
-            current = nodes.get(current).unwrap().0;
-        } else {
-            current = nodes.get(current).unwrap().1;
-        }
-
-        i += 1;
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_input(input)
     }
 
-    i
-}
+    fn part1(input: &Self::Input<'_>) -> usize {
+        let (instructions, nodes) = input;
 
-pub fn part2(input: &Vec<&str>) -> usize {
-    let (instructions, nodes) = parse_input(input);
+        let mut current = "AAA";
 
-    let mut current = nodes
-        .keys()
-        .filter(|name| name.ends_with("A"))
-        .map(|&name| name)
-        .collect::<Vec<&str>>();
+        let mut i = 0;
+        while current != "ZZZ" {
+            let instruction = instructions[i % instructions.len()];
 
-    let paths_count = current.len();
-    let mut steps_to_end = Vec::new();
+            if instruction == 'L' {
+                current = nodes.get(current).unwrap().0;
+            } else {
+                current = nodes.get(current).unwrap().1;
+            }
 
-    let mut i = 0;
-    while steps_to_end.len() != paths_count {
-        let instruction = instructions[i % instructions.len()];
-        i += 1;
+            i += 1;
+        }
 
-        current = current
-            .iter()
-            .map(|name| {
-                let node = nodes.get(name).unwrap();
-
-                if instruction == 'L' {
-                    node.0
-                } else {
-                    node.1
-                }
-            })
-            .filter(|name| {
-                if name.ends_with("Z") {
-                    steps_to_end.push(i);
+        i
+    }
 
-                    return false;
-                }
+    fn part2(input: &Self::Input<'_>) -> usize {
+        let (instructions, nodes) = input;
+
+        let mut current = nodes
+            .keys()
+            .filter(|name| name.ends_with("A"))
+            .map(|&name| name)
+            .collect::<Vec<&str>>();
+
+        let paths_count = current.len();
+        let mut steps_to_end = Vec::new();
+
+        let mut i = 0;
+        while steps_to_end.len() != paths_count {
+            let instruction = instructions[i % instructions.len()];
+            i += 1;
+
+            current = current
+                .iter()
+                .map(|name| {
+                    let node = nodes.get(name).unwrap();
+
+                    if instruction == 'L' {
+                        node.0
+                    } else {
+                        node.1
+                    }
+                })
+                .filter(|name| {
+                    if name.ends_with("Z") {
+                        steps_to_end.push(i);
+
+                        return false;
+                    }
+
+                    true
+                })
+                .collect();
+        }
 
-                true
+        steps_to_end
+            .iter()
+            .skip(1)
+            .fold(*steps_to_end.first().unwrap(), |acc, &x| {
+                least_common_multiple(acc, x)
             })
-            .collect();
     }
-
-    steps_to_end
-        .iter()
-        .skip(1)
-        .fold(*steps_to_end.first().unwrap(), |acc, &x| {
-            least_common_multiple(acc, x)
-        })
 }
 
-fn parse_input<'a>(input: &Vec<&'a str>) -> (Vec<char>, HashMap<&'a str, (&'a str, &'a str)>) {
-    let mut iter = input.iter();
+fn parse_input(input: &str) -> (Vec<char>, HashMap<&str, (&str, &str)>) {
+    let lines: Vec<&str> = input_lines(input);
+    let mut iter = lines.into_iter();
 
     let instructions = iter.next().unwrap().chars().collect::<Vec<char>>();
 
     let nodes = iter
         .map(|line| {
-            let (name, values) = line.split_once(" = ").unwrap();
-
-            let (left, right) = values
-                .trim_start_matches('(')
-                .trim_end_matches(')')
-                .split_once(", ")
-                .unwrap();
+            let (name, left, right) = scan_in!(input, line, "{} = ({}, {})", &str, &str, &str)
+                .unwrap_or_else(|error| error.raise());
 
             (name, (left, right))
         })
@@ -112,13 +121,11 @@ fn greatest_common_divisor(a: usize, b: usize) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use helpers::input_lines;
-
     use super::*;
 
     #[test]
     fn test_part1() {
-        let input = input_lines(
+        let input = Day8::parse(
             "
 LLR
 
@@ -127,12 +134,12 @@ BBB = (AAA, ZZZ)
 ZZZ = (ZZZ, ZZZ)",
         );
 
-        assert_eq!(part1(&input), 6)
+        assert_eq!(Day8::part1(&input), 6)
     }
 
     #[test]
     fn test_part2() {
-        let input = input_lines(
+        let input = Day8::parse(
             "
 LR
 
@@ -146,6 +153,6 @@ LR
 XXX = (XXX, XXX)",
         );
 
-        assert_eq!(part2(&input), 6)
+        assert_eq!(Day8::part2(&input), 6)
     }
 }
@@ -1,38 +1,50 @@
+use helpers::{input_lines, Solution};
 use itertools::Itertools;
 
-pub fn part1(input: &Vec<&str>) -> isize {
-    input
-        .iter()
-        .map(|line| {
-            calc_sequence(line)
-                .iter()
-                .map(|vec| vec.last().unwrap())
-                .sum::<isize>()
-        })
-        .sum()
-}
+pub struct Day9;
 
-pub fn part2(input: &Vec<&str>) -> isize {
-    input
-        .iter()
-        .map(|line| {
-            calc_sequence(line)
-                .iter()
-                .rev()
-                .fold(0, |acc, vec| vec.first().unwrap() - acc)
-        })
-        .sum()
-}
+impl Solution for Day9 {
+    type Input<'a> = Vec<Vec<isize>>;
+    type Answer = isize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input_lines::<Vec<&str>>(input)
+            .iter()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|x| x.parse().unwrap())
+                    .collect()
+            })
+            .collect()
+    }
 
-fn calc_sequence(line: &str) -> Vec<Vec<isize>> {
-    let numbers: Vec<isize> = line
-        .split_whitespace()
-        .map(|x| x.parse().unwrap())
-        .collect();
+    fn part1(input: &Self::Input<'_>) -> isize {
+        input
+            .iter()
+            .map(|numbers| {
+                calc_sequence(numbers)
+                    .iter()
+                    .map(|vec| vec.last().unwrap())
+                    .sum::<isize>()
+            })
+            .sum()
+    }
 
-    let mut sequence = Vec::new();
+    fn part2(input: &Self::Input<'_>) -> isize {
+        input
+            .iter()
+            .map(|numbers| {
+                calc_sequence(numbers)
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, vec| vec.first().unwrap() - acc)
+            })
+            .sum()
+    }
+}
 
-    sequence.push(numbers);
+fn calc_sequence(numbers: &[isize]) -> Vec<Vec<isize>> {
+    let mut sequence = vec![numbers.to_vec()];
 
     while sequence.last().unwrap().iter().any(|&x| x != 0) {
         sequence.push(
@@ -51,25 +63,23 @@ fn calc_sequence(line: &str) -> Vec<Vec<isize>> {
 
 #[cfg(test)]
 mod tests {
-    use helpers::input_lines;
-
     use super::*;
 
-    fn input<'a>() -> Vec<&'a str> {
+    fn input() -> Vec<Vec<isize>> {
         let input = "
 0 3 6 9 12 15
 1 3 6 10 15 21
 10 13 16 21 30 45";
-        input_lines(input)
+        Day9::parse(input)
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(&input()), 114)
+        assert_eq!(Day9::part1(&input()), 114)
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(&input()), 2)
+        assert_eq!(Day9::part2(&input()), 2)
     }
 }
@@ -1,8 +1,11 @@
-use helpers::{input_lines, run};
+use day9::Day9;
+use helpers::{day, input};
 
 fn main() {
-    let input: Vec<&str> = input_lines(include_str!("../input.txt"));
+    let input = input!();
+    let mut day = day!();
 
-    run("part1", || day9::part1(&input));
-    run("part2", || day9::part2(&input));
+    day.solve::<Day9>(&input);
+
+    day.finish();
 }
@@ -0,0 +1,280 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::ParseStream;
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Expr, ExprArray, Fields, GenericParam, Lifetime,
+    LitStr, Type,
+};
+
+/// Derives `helpers::PuzzleParse`.
+///
+/// Structs are read from a line field by field. Each field can be preceded by
+/// `#[puzzle(prefix = "...")]` and followed by `#[puzzle(suffix = "...")]`; fields after the first
+/// default to a single space prefix. `#[puzzle(sep = ", ")]` splits a list (`Vec`, `VecDeque`,
+/// `HashSet` or `BTreeSet`) or tuple field, and `#[puzzle(sep = ["; ", ", ", " "])]` gives one
+/// separator per level of nesting. Lists and tuples without a separator split on whitespace.
+///
+/// Enums of unit variants match the lowercase variant name, or `#[puzzle(name = "...")]`.
+#[proc_macro_derive(PuzzleParse, attributes(puzzle))]
+pub fn derive_puzzle_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let lifetimes: Vec<&Lifetime> = input
+        .generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(param) => Ok(&param.lifetime),
+            param => Err(Error::new(
+                param.span(),
+                "PuzzleParse only supports a lifetime parameter",
+            )),
+        })
+        .collect::<syn::Result<_>>()?;
+
+    let (impl_lifetime, lifetime) = match lifetimes.as_slice() {
+        [] => (
+            Some(quote!('puzzle)),
+            Lifetime::new("'puzzle", proc_macro2::Span::call_site()),
+        ),
+        [lifetime] => (None, (*lifetime).clone()),
+        _ => {
+            return Err(Error::new(
+                input.generics.span(),
+                "PuzzleParse supports at most one lifetime parameter",
+            ))
+        }
+    };
+
+    let body = match &input.data {
+        Data::Struct(data) => expand_struct(&data.fields)?,
+        Data::Enum(data) => expand_enum(data)?,
+        Data::Union(_) => {
+            return Err(Error::new(
+                input.ident.span(),
+                "PuzzleParse can't be derived for unions",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let (_, type_generics, _) = input.generics.split_for_impl();
+    let generics = match impl_lifetime {
+        Some(impl_lifetime) => quote!(<#impl_lifetime>),
+        None => quote!(<#lifetime>),
+    };
+
+    Ok(quote! {
+        impl #generics ::helpers::PuzzleParse<#lifetime> for #name #type_generics {
+            fn parse_in(
+                input: &#lifetime str,
+                text: &#lifetime str,
+            ) -> ::std::result::Result<Self, ::helpers::InputError> {
+                #body
+            }
+        }
+    })
+}
+
+#[derive(Default)]
+struct FieldOptions {
+    prefix: Option<LitStr>,
+    suffix: Option<LitStr>,
+    separators: Vec<LitStr>,
+}
+
+fn field_options(attributes: &[syn::Attribute]) -> syn::Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+
+    for attribute in attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("puzzle"))
+    {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("prefix") {
+                options.prefix = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("suffix") {
+                options.suffix = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("sep") {
+                options.separators = parse_separators(meta.value()?)?;
+            } else {
+                return Err(meta.error("expected `prefix`, `suffix` or `sep`"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(options)
+}
+
+fn parse_separators(value: ParseStream) -> syn::Result<Vec<LitStr>> {
+    if value.peek(syn::token::Bracket) {
+        let array: ExprArray = value.parse()?;
+        array
+            .elems
+            .iter()
+            .map(|element| match element {
+                Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(separator),
+                    ..
+                }) => Ok(separator.clone()),
+                element => Err(Error::new(element.span(), "expected a string separator")),
+            })
+            .collect()
+    } else {
+        Ok(vec![value.parse()?])
+    }
+}
+
+fn expand_struct(fields: &Fields) -> syn::Result<TokenStream2> {
+    let mut pattern = String::new();
+    let mut values = vec![];
+
+    for (index, field) in fields.iter().enumerate() {
+        let options = field_options(&field.attrs)?;
+
+        match &options.prefix {
+            Some(prefix) => pattern.push_str(&escape(&prefix.value())),
+            None if index > 0 => pattern.push(' '),
+            None => {}
+        }
+        pattern.push_str("{}");
+        if let Some(suffix) = &options.suffix {
+            pattern.push_str(&escape(&suffix.value()));
+        }
+
+        let value = expand_value(
+            &field.ty,
+            &options.separators,
+            quote!(fields[#index].text()),
+        )?;
+        values.push(match &field.ident {
+            Some(ident) => quote!(#ident: #value),
+            None => value,
+        });
+    }
+
+    let construct = match fields {
+        Fields::Named(_) => quote!(Self { #(#values,)* }),
+        Fields::Unnamed(_) => quote!(Self(#(#values,)*)),
+        Fields::Unit => quote!(Self),
+    };
+
+    Ok(quote! {
+        let fields = ::helpers::scan_line(input, text.trim(), #pattern)?;
+        ::std::result::Result::Ok(#construct)
+    })
+}
+
+fn expand_value(ty: &Type, separators: &[LitStr], text: TokenStream2) -> syn::Result<TokenStream2> {
+    let (separator, inner) = match separators.split_first() {
+        Some((separator, inner)) => (quote!(::std::option::Option::Some(#separator)), inner),
+        None => (quote!(::std::option::Option::None), separators),
+    };
+
+    if let Some(item) = list_item(ty) {
+        let item = expand_value(item, inner, quote!(item))?;
+        return Ok(quote! {
+            ::helpers::split_list(#text, #separator)
+                .into_iter()
+                .map(|item| -> ::std::result::Result<_, ::helpers::InputError> {
+                    let value = #item;
+                    ::std::result::Result::Ok(value)
+                })
+                .collect::<::std::result::Result<#ty, ::helpers::InputError>>()?
+        });
+    }
+
+    if let Type::Tuple(tuple) = ty {
+        let len = tuple.elems.len();
+        let items = tuple
+            .elems
+            .iter()
+            .enumerate()
+            .map(|(index, element)| expand_value(element, inner, quote!(parts[#index])))
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        return Ok(quote! {{
+            let parts = ::helpers::split_tuple::<#ty>(input, #text, #separator, #len)?;
+            (#(#items,)*)
+        }});
+    }
+
+    Ok(quote!(<#ty as ::helpers::PuzzleParse>::parse_in(input, #text)?))
+}
+
+// The item type of the collections read as separated lists.
+fn list_item(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if !["Vec", "VecDeque", "HashSet", "BTreeSet"]
+        .iter()
+        .any(|name| segment.ident == name)
+    {
+        return None;
+    }
+
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(arguments) => match arguments.args.first()? {
+            syn::GenericArgument::Type(item) => Some(item),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn expand_enum(data: &syn::DataEnum) -> syn::Result<TokenStream2> {
+    let mut names = vec![];
+    let mut arms = vec![];
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(
+                variant.span(),
+                "PuzzleParse only supports unit variants",
+            ));
+        }
+
+        let mut name = variant.ident.to_string().to_lowercase();
+        for attribute in variant
+            .attrs
+            .iter()
+            .filter(|attribute| attribute.path().is_ident("puzzle"))
+        {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    name = meta.value()?.parse::<LitStr>()?.value();
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `name`"))
+                }
+            })?;
+        }
+
+        let ident = &variant.ident;
+        arms.push(quote!(#name => ::std::result::Result::Ok(Self::#ident),));
+        names.push(name);
+    }
+
+    Ok(quote! {
+        let text = text.trim();
+        match text {
+            #(#arms)*
+            _ => ::std::result::Result::Err(::helpers::unknown_variant::<Self>(input, text, &[#(#names),*])),
+        }
+    })
+}
+
+fn escape(literal: &str) -> String {
+    literal.replace('{', "{{").replace('}', "}}")
+}
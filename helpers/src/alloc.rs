@@ -0,0 +1,205 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    pub peak: u64,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {}, peak {}",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+// The test binary keeps the system allocator, so tracking is only reported outside of tests.
+#[cfg(all(feature = "count-alloc", not(test)))]
+pub(crate) fn track<F, R>(f: F) -> (R, Option<AllocStats>)
+where
+    F: FnOnce() -> R,
+{
+    let (result, stats) = counting::track(f);
+    (result, Some(stats))
+}
+
+#[cfg(any(not(feature = "count-alloc"), test))]
+pub(crate) fn track<F, R>(f: F) -> (R, Option<AllocStats>)
+where
+    F: FnOnce() -> R,
+{
+    (f(), None)
+}
+
+// Counters are per thread so parts solved concurrently don't see each other's allocations.
+#[cfg(feature = "count-alloc")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    use super::AllocStats;
+
+    #[cfg_attr(not(test), global_allocator)]
+    pub(super) static GLOBAL: Counting = Counting;
+
+    pub(super) struct Counting;
+
+    #[derive(Copy, Clone)]
+    struct Counters {
+        allocations: u64,
+        bytes: u64,
+        live: i64,
+        peak: i64,
+    }
+
+    thread_local! {
+        static COUNTERS: Cell<Counters> = const {
+            Cell::new(Counters {
+                allocations: 0,
+                bytes: 0,
+                live: 0,
+                peak: 0,
+            })
+        };
+    }
+
+    fn update<F: FnOnce(&mut Counters)>(change: F) {
+        let _ = COUNTERS.try_with(|cell| {
+            let mut counters = cell.get();
+            change(&mut counters);
+            counters.peak = counters.peak.max(counters.live);
+            cell.set(counters);
+        });
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            update(|counters| {
+                counters.allocations += 1;
+                counters.bytes += layout.size() as u64;
+                counters.live += layout.size() as i64;
+            });
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            update(|counters| {
+                counters.allocations += 1;
+                counters.bytes += layout.size() as u64;
+                counters.live += layout.size() as i64;
+            });
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            update(|counters| counters.live -= layout.size() as i64);
+            System.dealloc(ptr, layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            update(|counters| {
+                counters.allocations += 1;
+                counters.bytes += new_size as u64;
+                counters.live += new_size as i64 - layout.size() as i64;
+            });
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    #[cfg_attr(test, allow(dead_code))]
+    pub(super) fn track<F, R>(f: F) -> (R, AllocStats)
+    where
+        F: FnOnce() -> R,
+    {
+        let start = COUNTERS.with(|cell| {
+            let mut counters = cell.get();
+            counters.peak = counters.live;
+            cell.set(counters);
+            counters
+        });
+
+        let result = f();
+
+        let end = COUNTERS.with(Cell::get);
+        let stats = AllocStats {
+            allocations: end.allocations - start.allocations,
+            bytes: end.bytes - start.bytes,
+            peak: (end.peak - start.live).max(0) as u64,
+        };
+
+        (result, stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00 MiB");
+    }
+
+    #[test]
+    fn test_display() {
+        let stats = AllocStats {
+            allocations: 12,
+            bytes: 2048,
+            peak: 512,
+        };
+
+        assert_eq!(stats.to_string(), "12 allocs, 2.00 KiB, peak 512 B");
+    }
+
+    #[cfg(feature = "count-alloc")]
+    #[test]
+    fn test_counting() {
+        use std::alloc::{GlobalAlloc, Layout};
+
+        let (_, stats) = counting::track(|| unsafe {
+            let big = Layout::array::<u64>(100).unwrap();
+            let small = Layout::array::<u8>(10).unwrap();
+
+            let values = counting::GLOBAL.alloc(big);
+            counting::GLOBAL.dealloc(values, big);
+            let values = counting::GLOBAL.alloc_zeroed(small);
+            let values = counting::GLOBAL.realloc(values, small, 20);
+            counting::GLOBAL.dealloc(values, Layout::array::<u8>(20).unwrap());
+        });
+
+        assert_eq!(stats.allocations, 3);
+        assert_eq!(stats.bytes, 830);
+        assert_eq!(stats.peak, 800);
+    }
+
+    #[test]
+    fn test_track_in_tests() {
+        assert_eq!(track(|| 42), (42, None));
+    }
+}
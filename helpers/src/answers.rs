@@ -0,0 +1,209 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::{fs, io};
+
+use crate::{RunReport, Status};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    values: BTreeMap<String, String>,
+}
+
+impl Answers {
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => Answers::parse(&content)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut values = BTreeMap::new();
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("Line {}: expected `part = \"answer\"`", index + 1))?;
+
+            values.insert(
+                key.trim().to_string(),
+                parse_value(value.trim()).ok_or_else(|| {
+                    format!("Line {}: invalid value `{}`", index + 1, value.trim())
+                })?,
+            );
+        }
+
+        Ok(Answers { values })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, part: &str) -> Option<&str> {
+        self.values.get(part).map(|value| value.as_str())
+    }
+
+    pub fn set(&mut self, part: &str, answer: &str) {
+        self.values.insert(part.to_string(), answer.to_string());
+    }
+
+    pub fn verify(&self, report: &mut RunReport) {
+        report.status = match self.get(&report.part) {
+            Some(expected) if expected == report.answer => Status::Correct,
+            Some(expected) => Status::Wrong {
+                expected: expected.to_string(),
+            },
+            None => Status::Unknown,
+        };
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = String::new();
+
+        for (key, value) in &self.values {
+            write!(output, "{} = \"", key)?;
+            for char in value.chars() {
+                match char {
+                    '"' => output.push_str("\\\""),
+                    '\\' => output.push_str("\\\\"),
+                    '\n' => output.push_str("\\n"),
+                    char => output.push(char),
+                }
+            }
+            writeln!(output, "\"")?;
+        }
+
+        f.write_str(&output)
+    }
+}
+
+fn parse_value(value: &str) -> Option<String> {
+    let value = match value.find(" #") {
+        Some(index) if !value.starts_with('"') => value[..index].trim_end(),
+        _ => value,
+    };
+
+    if let Some(quoted) = value.strip_prefix('"') {
+        let mut output = String::new();
+        let mut chars = quoted.chars();
+
+        while let Some(char) = chars.next() {
+            match char {
+                '"' => {
+                    let rest = chars.as_str().trim();
+                    return (rest.is_empty() || rest.starts_with('#')).then_some(output);
+                }
+                '\\' => match chars.next()? {
+                    'n' => output.push('\n'),
+                    't' => output.push('\t'),
+                    escaped => output.push(escaped),
+                },
+                char => output.push(char),
+            }
+        }
+
+        None
+    } else if !value.is_empty()
+        && value
+            .chars()
+            .all(|char| char == '-' || char.is_ascii_digit())
+    {
+        Some(value.to_string())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "
+# Day 1
+part1 = \"54632\"
+part2 = 54019 # integers don't need quotes
+part3 = \"say \\\"hi\\\"\" # comment
+",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get("part1"), Some("54632"));
+        assert_eq!(answers.get("part2"), Some("54019"));
+        assert_eq!(answers.get("part3"), Some("say \"hi\""));
+        assert_eq!(answers.get("part4"), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Answers::parse("part1 54632"),
+            Err("Line 1: expected `part = \"answer\"`".to_string())
+        );
+        assert_eq!(
+            Answers::parse("\npart1 = abc"),
+            Err("Line 2: invalid value `abc`".to_string())
+        );
+        assert!(Answers::parse("part1 = \"unterminated").is_err());
+    }
+
+    #[test]
+    fn test_to_string_round_trip() {
+        let mut answers = Answers::default();
+        answers.set("part2", "-12");
+        answers.set("part1", "a \"quoted\" answer");
+
+        let content = answers.to_string();
+
+        assert_eq!(
+            content,
+            "part1 = \"a \\\"quoted\\\" answer\"\npart2 = \"-12\"\n"
+        );
+        assert_eq!(Answers::parse(&content), Ok(answers));
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse("part1 = 10\npart2 = 20").unwrap();
+        let mut correct = RunReport::new(Some(1), "part1", "10".to_string(), Duration::ZERO);
+        let mut wrong = RunReport::new(Some(1), "part2", "21".to_string(), Duration::ZERO);
+        let mut unknown = RunReport::new(Some(1), "part3", "30".to_string(), Duration::ZERO);
+
+        answers.verify(&mut correct);
+        answers.verify(&mut wrong);
+        answers.verify(&mut unknown);
+
+        assert_eq!(correct.status, Status::Correct);
+        assert_eq!(
+            wrong.status,
+            Status::Wrong {
+                expected: "20".to_string()
+            }
+        );
+        assert_eq!(unknown.status, Status::Unknown);
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        assert_eq!(
+            Answers::load("does/not/exist/answers.toml").unwrap(),
+            Answers::default()
+        );
+    }
+}
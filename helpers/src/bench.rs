@@ -0,0 +1,253 @@
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::instant::BasicInstant;
+
+const DEFAULT_WARMUP: usize = 3;
+const DEFAULT_BUDGET: Duration = Duration::from_secs(1);
+const MAX_SAMPLES: usize = 100_000;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Bench {
+    pub warmup: usize,
+    pub limit: Limit,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Limit {
+    Iterations(usize),
+    Time(Duration),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Bench {
+            warmup: DEFAULT_WARMUP,
+            limit: Limit::Time(DEFAULT_BUDGET),
+        }
+    }
+}
+
+impl Bench {
+    pub fn iterations(iterations: usize) -> Self {
+        Bench {
+            limit: Limit::Iterations(iterations.max(1)),
+            ..Bench::default()
+        }
+    }
+
+    pub fn time(budget: Duration) -> Self {
+        Bench {
+            limit: Limit::Time(budget),
+            ..Bench::default()
+        }
+    }
+
+    fn is_done(&self, samples: usize, total: Duration) -> bool {
+        match self.limit {
+            Limit::Iterations(iterations) => samples >= iterations,
+            Limit::Time(budget) => total >= budget || samples >= MAX_SAMPLES,
+        }
+    }
+}
+
+/// Parses the `AOC_BENCH` value: `on` or `true` for the defaults, a plain number
+/// for a fixed amount of iterations, or a number suffixed with `ms` or `s` for a time budget.
+impl FromStr for Bench {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim().to_ascii_lowercase();
+        let error = || format!("Invalid benchmark setting `{}`", s);
+
+        if value.is_empty() || value == "on" || value == "true" {
+            Ok(Bench::default())
+        } else if let Some(budget) = parse_duration(&value) {
+            Ok(Bench::time(budget))
+        } else {
+            Ok(Bench::iterations(value.parse().map_err(|_| error())?))
+        }
+    }
+}
+
+// Parses a number suffixed with `ms` or `s`, e.g. `250ms` or `1.5s`.
+pub(crate) fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+
+    if let Some(millis) = value.strip_suffix("ms") {
+        Some(Duration::from_millis(millis.trim().parse().ok()?))
+    } else {
+        let seconds: f64 = value.strip_suffix('s')?.trim().parse().ok()?;
+        Duration::try_from_secs_f64(seconds).ok()
+    }
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let count = sorted.len();
+        let median = if count.is_multiple_of(2) {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2
+        } else {
+            sorted[count / 2]
+        };
+
+        let p95_rank = (count as f64 * 0.95).ceil() as usize;
+        let p95 = sorted[p95_rank.clamp(1, count) - 1];
+
+        let nanos: Vec<f64> = sorted
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect();
+        let mean = nanos.iter().sum::<f64>() / count as f64;
+        let variance = nanos
+            .iter()
+            .map(|sample| (sample - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        Some(Stats {
+            samples: count,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            p95,
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+pub(crate) fn measure<N, S, R>(solver: S, bench: &Bench) -> (R, Stats)
+where
+    N: BasicInstant,
+    S: Fn() -> R,
+{
+    for _ in 0..bench.warmup {
+        black_box(solver());
+    }
+
+    let mut samples = Vec::new();
+    let mut total = Duration::ZERO;
+
+    loop {
+        let now = N::now();
+        let solution = black_box(solver());
+        let elapsed = now.elapsed();
+
+        samples.push(elapsed);
+        total += elapsed;
+
+        if bench.is_done(samples.len(), total) {
+            let stats = Stats::from_samples(&samples).unwrap();
+            return (solution, stats);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use crate::instant;
+
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values
+            .iter()
+            .map(|&value| Duration::from_millis(value))
+            .collect()
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let stats = Stats::from_samples(&millis(&[5, 1, 4, 2, 3])).unwrap();
+
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+        assert_eq!(stats.stddev, Duration::from_nanos(1_414_214));
+    }
+
+    #[test]
+    fn test_stats_median_of_even_samples() {
+        let stats = Stats::from_samples(&millis(&[4, 1, 3, 2])).unwrap();
+
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.p95, Duration::from_millis(4));
+    }
+
+    #[test]
+    fn test_stats_without_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_measure_iterations() {
+        instant::fake::with_fake_samples(&millis(&[30, 10, 20]));
+        let calls = Cell::new(0);
+
+        let (solution, stats) = measure::<instant::fake::Instant, _, _>(
+            || {
+                calls.set(calls.get() + 1);
+                42
+            },
+            &Bench {
+                warmup: 2,
+                limit: Limit::Iterations(3),
+            },
+        );
+
+        assert_eq!(solution, 42);
+        assert_eq!(calls.get(), 5);
+        assert_eq!(stats.samples, 3);
+        assert_eq!(stats.min, Duration::from_millis(10));
+        assert_eq!(stats.median, Duration::from_millis(20));
+    }
+
+    #[test]
+    fn test_measure_time_budget() {
+        instant::fake::with_fake_elapsed(Duration::from_millis(10));
+
+        let (_, stats) = measure::<instant::fake::Instant, _, _>(
+            || 42,
+            &Bench {
+                warmup: 0,
+                limit: Limit::Time(Duration::from_millis(45)),
+            },
+        );
+
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.mean, Duration::from_millis(10));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_bench_from_str() {
+        assert_eq!("".parse(), Ok(Bench::default()));
+        assert_eq!("on".parse(), Ok(Bench::default()));
+        assert_eq!("50".parse(), Ok(Bench::iterations(50)));
+        assert_eq!("250ms".parse(), Ok(Bench::time(Duration::from_millis(250))));
+        assert_eq!("2s".parse(), Ok(Bench::time(Duration::from_secs(2))));
+        assert!("fast".parse::<Bench>().is_err());
+    }
+}
@@ -0,0 +1,99 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+thread_local! {
+    static CURRENT: RefCell<CancelToken> = RefCell::new(CancelToken::default());
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// The token of the part running on this thread, never cancelled outside a timed run.
+    pub fn current() -> Self {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+// Makes `token` the one `CancelToken::current` returns on this thread.
+pub(crate) fn set_current(token: CancelToken) {
+    CURRENT.with(|current| *current.borrow_mut() = token);
+}
+
+// Runs `f` on a worker thread and gives up once `limit` passes, cancelling its token. The worker
+// is detached, so one that never checks the token keeps running in the background while the caller
+// reports the timeout and moves on.
+pub(crate) fn with_timeout<F, R>(limit: Duration, f: F) -> Option<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let token = CancelToken::default();
+    let (sender, receiver) = mpsc::channel();
+
+    let worker_token = token.clone();
+    thread::spawn(move || {
+        set_current(worker_token);
+        let _ = sender.send(f());
+    });
+
+    match receiver.recv_timeout(limit) {
+        Ok(result) => Some(result),
+        Err(_) => {
+            token.cancel();
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_timeout() {
+        assert_eq!(with_timeout(Duration::from_secs(5), || 42), Some(42));
+    }
+
+    #[test]
+    fn test_with_timeout_cancels() {
+        let result = with_timeout(Duration::from_millis(10), || {
+            let token = CancelToken::current();
+            let mut iterations = 0u64;
+            while !token.is_cancelled() {
+                iterations += 1;
+            }
+            iterations
+        });
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_with_timeout_ignored() {
+        let result = with_timeout(Duration::from_millis(10), || loop {
+            thread::sleep(Duration::from_millis(10));
+        });
+
+        assert_eq!(result, None::<()>);
+        assert_eq!(with_timeout(Duration::from_secs(5), || 42), Some(42));
+    }
+
+    #[test]
+    fn test_current_outside_timed_run() {
+        assert!(!CancelToken::current().is_cancelled());
+    }
+}
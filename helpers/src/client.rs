@@ -0,0 +1,324 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{env, fs, io};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_YEAR: u16 = 2023;
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const USER_AGENT: &str = "github.com/mariomka/AdventOfCode2023 by mario@mjp.one";
+
+pub struct Client {
+    base_url: String,
+    year: u16,
+    pub(crate) session: String,
+    pub(crate) agent: ureq::Agent,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Download {
+    Fetched,
+    Cached,
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession(Option<PathBuf>),
+    Http { status: u16, body: String },
+    Transport(String),
+    Io(PathBuf, io::Error),
+    UnexpectedResponse(String),
+    InvalidPart(String),
+    AlreadySolved(String),
+    Cooldown(Duration),
+}
+
+impl Client {
+    pub fn new(session: &str) -> Self {
+        Client {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            year: DEFAULT_YEAR,
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    pub fn from_env() -> Result<Self, ClientError> {
+        let session = resolve_session(env::var(SESSION_ENV).ok(), session_file())?;
+        let client = Client::new(&session);
+
+        Ok(match env::var(BASE_URL_ENV) {
+            Ok(base_url) if !base_url.is_empty() => client.with_base_url(&base_url),
+            _ => client,
+        })
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_year(mut self, year: u16) -> Self {
+        self.year = year;
+        self
+    }
+
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .get(&self.url(day, "/input"))
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        read_response(response)
+    }
+
+    pub fn download_input<P: AsRef<Path>>(
+        &self,
+        day: u8,
+        path: P,
+    ) -> Result<Download, ClientError> {
+        let path = path.as_ref();
+
+        if fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(Download::Cached);
+        }
+
+        let input = self.input(day)?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|error| ClientError::Io(parent.to_path_buf(), error))?;
+        }
+        fs::write(path, input).map_err(|error| ClientError::Io(path.to_path_buf(), error))?;
+
+        Ok(Download::Fetched)
+    }
+
+    pub(crate) fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, self.year, day, path)
+    }
+}
+
+pub(crate) fn read_response(
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|error| ClientError::Transport(error.to_string())),
+        Err(ureq::Error::Status(status, response)) => Err(ClientError::Http {
+            status,
+            body: response.into_string().unwrap_or_default(),
+        }),
+        Err(ureq::Error::Transport(error)) => Err(ClientError::Transport(error.to_string())),
+    }
+}
+
+fn session_file() -> Option<PathBuf> {
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+
+    Some(config.join("aoc").join("session"))
+}
+
+fn resolve_session(
+    env_value: Option<String>,
+    file: Option<PathBuf>,
+) -> Result<String, ClientError> {
+    if let Some(session) = env_value.filter(|session| !session.trim().is_empty()) {
+        return Ok(session.trim().to_string());
+    }
+
+    file.as_ref()
+        .and_then(|file| fs::read_to_string(file).ok())
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+        .ok_or(ClientError::MissingSession(file))
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::MissingSession(Some(file)) => write!(
+                f,
+                "Missing session token: set {} or write it to {}",
+                SESSION_ENV,
+                file.display()
+            ),
+            ClientError::MissingSession(None) => {
+                write!(f, "Missing session token: set {}", SESSION_ENV)
+            }
+            ClientError::Http { status: 404, .. } => {
+                write!(f, "Puzzle not found (404), is the day unlocked?")
+            }
+            ClientError::Http { status, body } if *status == 400 || *status == 500 => write!(
+                f,
+                "Request rejected ({}), is the session token still valid? {}",
+                status,
+                body.trim()
+            ),
+            ClientError::Http { status, body } => {
+                write!(f, "Request failed ({}): {}", status, body.trim())
+            }
+            ClientError::Transport(error) => write!(f, "Request failed: {}", error),
+            ClientError::Io(path, error) => write!(f, "{}: {}", path.display(), error),
+            ClientError::UnexpectedResponse(message) => {
+                write!(f, "Unexpected response: {}", message)
+            }
+            ClientError::InvalidPart(part) => {
+                write!(f, "Invalid part `{}`, expected part1 or part2", part)
+            }
+            ClientError::AlreadySolved(answer) => {
+                write!(f, "Already solved, the answer is {}", answer)
+            }
+            ClientError::Cooldown(remaining) => write!(
+                f,
+                "Submitted too recently, wait {}s before trying again",
+                remaining.as_secs()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    use super::*;
+
+    pub struct Request {
+        pub head: String,
+        pub body: String,
+    }
+
+    pub fn serve(status: &str, body: &str) -> (String, JoinHandle<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut head = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+                head.push_str(&line);
+            }
+
+            let length = head
+                .lines()
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    name.eq_ignore_ascii_case("content-length")
+                        .then(|| value.trim().parse::<usize>().ok())?
+                })
+                .unwrap_or(0);
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+
+            Request {
+                head,
+                body: String::from_utf8(body).unwrap(),
+            }
+        });
+
+        (base_url, handle)
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("helpers-client-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        dir.join("day1").join("input.txt")
+    }
+
+    #[test]
+    fn test_input() {
+        let (base_url, server) = serve("200 OK", "1\n2\n3\n");
+        let client = Client::new("secret\n").with_base_url(&format!("{}/", base_url));
+
+        assert_eq!(client.input(7).unwrap(), "1\n2\n3\n");
+
+        let request = server.join().unwrap();
+        assert!(request
+            .head
+            .starts_with("GET /2023/day/7/input HTTP/1.1\r\n"));
+        assert!(request.head.contains("Cookie: session=secret\r\n"));
+        assert!(request
+            .head
+            .contains(&format!("User-Agent: {}\r\n", USER_AGENT)));
+        assert!(request.body.is_empty());
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let (base_url, server) = serve("404 Not Found", "Not found");
+        let client = Client::new("secret")
+            .with_base_url(&base_url)
+            .with_year(2015);
+
+        let error = client.input(25).unwrap_err();
+
+        assert!(matches!(error, ClientError::Http { status: 404, .. }));
+        assert!(server
+            .join()
+            .unwrap()
+            .head
+            .starts_with("GET /2015/day/25/input "));
+    }
+
+    #[test]
+    fn test_download_input_caches_file() {
+        let path = temp_path("cache");
+        let (base_url, server) = serve("200 OK", "input");
+        let client = Client::new("secret").with_base_url(&base_url);
+
+        assert_eq!(client.download_input(1, &path).unwrap(), Download::Fetched);
+        server.join().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "input");
+
+        let client = Client::new("secret").with_base_url("http://127.0.0.1:9");
+        assert_eq!(client.download_input(1, &path).unwrap(), Download::Cached);
+    }
+
+    #[test]
+    fn test_resolve_session() {
+        let file = env::temp_dir().join(format!("helpers-session-{}", std::process::id()));
+        fs::write(&file, "from-file\n").unwrap();
+
+        assert_eq!(
+            resolve_session(Some(" from-env ".into()), Some(file.clone())).unwrap(),
+            "from-env"
+        );
+        assert_eq!(
+            resolve_session(Some("".into()), Some(file.clone())).unwrap(),
+            "from-file"
+        );
+        assert!(matches!(
+            resolve_session(None, Some(file.with_extension("missing"))),
+            Err(ClientError::MissingSession(Some(_)))
+        ));
+    }
+}
@@ -0,0 +1,679 @@
+use std::fmt::Display;
+use std::io::{stdout, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::cancel::set_current;
+use crate::instant::BasicInstant;
+use crate::panic::{catch, Panic};
+use crate::profile::{profile, with_parse};
+use crate::run::{attempt, into_report, solve, solve_untimed, timed_out, write_report, Attempt};
+use crate::{Answers, CancelToken, Profile, RunOptions, RunReport, Solution, Status};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[macro_export]
+macro_rules! day {
+    () => {
+        $crate::Day::try_new(env!("CARGO_PKG_NAME"), env!("CARGO_MANIFEST_DIR")).unwrap_or_else(
+            |error| {
+                eprintln!("{}", error);
+                std::process::exit(2);
+            },
+        )
+    };
+}
+
+pub struct Day {
+    number: Option<u8>,
+    dir: PathBuf,
+    answers: Answers,
+    options: RunOptions,
+    reports: Vec<RunReport>,
+    quiet: bool,
+    parallel: bool,
+}
+
+impl Day {
+    pub fn new<P: Into<PathBuf>>(name: &str, dir: P) -> Self {
+        Self::try_new(name, dir).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Like `new`, returning an error instead of panicking when the answers file or the run options
+    /// in the environment are invalid.
+    pub fn try_new<P: Into<PathBuf>>(name: &str, dir: P) -> Result<Self, String> {
+        let dir = dir.into();
+        let answers = Answers::load(dir.join(ANSWERS_FILE))
+            .map_err(|error| format!("Invalid {}: {}", ANSWERS_FILE, error))?;
+
+        Ok(Day {
+            number: name.trim_start_matches("day").parse().ok(),
+            dir,
+            answers,
+            options: RunOptions::from_env()?,
+            reports: vec![],
+            quiet: false,
+            parallel: false,
+        })
+    }
+
+    pub fn quiet(mut self) -> Self {
+        self.quiet = true;
+        self
+    }
+
+    /// Solves part1 and part2 on separate threads, sharing the parsed input.
+    pub fn parallel(mut self) -> Self {
+        self.parallel = true;
+        self
+    }
+
+    pub fn timeout(mut self, limit: Duration) -> Self {
+        self.options.timeout = Some(limit);
+        self
+    }
+
+    pub fn number(&self) -> Option<u8> {
+        self.number
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn answers(&self) -> &Answers {
+        &self.answers
+    }
+
+    pub fn reports(&self) -> &[RunReport] {
+        &self.reports
+    }
+
+    pub fn run<S, R>(&mut self, part: &str, solver: S) -> &RunReport
+    where
+        R: Display + Send + 'static,
+        S: Fn() -> R + Send + Sync + 'static,
+    {
+        self.raw_run(stdout(), Instant::now(), part, solver)
+    }
+
+    pub fn solve<S: Solution + 'static>(&mut self, input: &str) {
+        self.raw_solve::<S, _, Instant>(stdout(), input)
+    }
+
+    /// Like `run`, writing to `writer` and timing with `N`, e.g. `testing::FakeInstant`.
+    #[cfg(feature = "test-support")]
+    pub fn run_with_clock<W, N, S, R>(&mut self, writer: W, part: &str, solver: S) -> &RunReport
+    where
+        W: Write,
+        N: BasicInstant,
+        S: Fn() -> R + Send + Sync + 'static,
+        R: Display + Send + 'static,
+    {
+        self.raw_run(writer, N::now(), part, solver)
+    }
+
+    /// Like `solve`, writing to `writer` and timing with `N`, e.g. `testing::FakeInstant`.
+    #[cfg(feature = "test-support")]
+    pub fn solve_with_clock<S, W, N>(&mut self, writer: W, input: &str)
+    where
+        S: Solution + 'static,
+        W: Write,
+        N: BasicInstant,
+    {
+        self.raw_solve::<S, W, N>(writer, input)
+    }
+
+    fn raw_run<W, N, S, R>(&mut self, writer: W, now: N, part: &str, solver: S) -> &RunReport
+    where
+        W: Write,
+        N: BasicInstant,
+        S: Fn() -> R + Send + Sync + 'static,
+        R: Display + Send + 'static,
+    {
+        let report = solve(now, part, &self.options, solver);
+        self.record(writer, report)
+    }
+
+    fn raw_solve<S, W, N>(&mut self, writer: W, input: &str)
+    where
+        S: Solution + 'static,
+        W: Write,
+        N: BasicInstant,
+    {
+        match self.options.timeout {
+            Some(limit) => self.solve_timed::<S, W, N>(writer, input, limit),
+            None => self.solve_untimed::<S, W, N>(writer, input),
+        }
+    }
+
+    fn solve_untimed<S, W, N>(&mut self, writer: W, input: &str)
+    where
+        S: Solution,
+        W: Write,
+        N: BasicInstant,
+    {
+        let now = N::now();
+        let (input, parse_profile) = match catch(|| profile(|| S::parse(input))) {
+            Ok(parsed) => parsed,
+            Err(panic) => return self.record_parse_panic(writer, &now, panic),
+        };
+        let parse_time = now.elapsed();
+
+        let options = &self.options;
+        let reports = both(
+            self.parallel,
+            || solve_untimed(N::now(), "part1", options, || S::part1(&input)),
+            || solve_untimed(N::now(), "part2", options, || S::part2(&input)),
+        );
+
+        self.record_parts(writer, parse_time, parse_profile, reports);
+    }
+
+    // Parsing and the parts run on a detached worker that owns the input, so one overrunning its
+    // limit can be left behind. The parts borrow the parsed input on threads scoped to the worker,
+    // each started on request so a sequential part2 doesn't wait for a stuck part1.
+    fn solve_timed<S, W, N>(&mut self, writer: W, input: &str, limit: Duration)
+    where
+        S: Solution + 'static,
+        W: Write,
+        N: BasicInstant,
+    {
+        let input = input.to_string();
+        let (parsed_sender, parsed_receiver) = mpsc::channel();
+        let (start_sender, start_receiver) = mpsc::channel::<(usize, CancelToken)>();
+        let (attempt_sender, attempt_receiver) = mpsc::channel();
+        let parse_token = CancelToken::default();
+        let bench = self.options.bench;
+
+        let now = N::now();
+        let worker_token = parse_token.clone();
+        thread::spawn(move || {
+            set_current(worker_token);
+            let parsed = match catch(|| profile(|| S::parse(&input))) {
+                Ok((parsed, parse_profile)) => {
+                    let _ = parsed_sender.send(Ok(parse_profile));
+                    parsed
+                }
+                Err(panic) => {
+                    let _ = parsed_sender.send(Err(panic));
+                    return;
+                }
+            };
+
+            let parsed = &parsed;
+            thread::scope(|scope| {
+                for (part, token) in start_receiver {
+                    let attempt_sender = attempt_sender.clone();
+                    scope.spawn(move || {
+                        set_current(token);
+                        let solver = || match part {
+                            0 => S::part1(parsed),
+                            _ => S::part2(parsed),
+                        };
+                        let _ = attempt_sender
+                            .send((part, attempt(&N::now(), bench.as_ref(), &solver)));
+                    });
+                }
+            });
+        });
+
+        let parse_profile = match parsed_receiver.recv_timeout(limit) {
+            Ok(Ok(parse_profile)) => parse_profile,
+            Ok(Err(panic)) => return self.record_parse_panic(writer, &now, panic),
+            Err(_) => {
+                parse_token.cancel();
+                self.record(writer, timed_out("parse", limit));
+                return;
+            }
+        };
+        let parse_time = now.elapsed();
+
+        let batches: &[&[usize]] = if self.parallel {
+            &[&[0, 1]]
+        } else {
+            &[&[0], &[1]]
+        };
+        let mut attempts: [Option<Attempt>; 2] = [None, None];
+
+        for &batch in batches {
+            let tokens = [CancelToken::default(), CancelToken::default()];
+            for &part in batch {
+                let _ = start_sender.send((part, tokens[part].clone()));
+            }
+
+            let deadline = Instant::now() + limit;
+            while batch.iter().any(|&part| attempts[part].is_none()) {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                match attempt_receiver.recv_timeout(remaining) {
+                    // A part that already timed out may still finish later, too late to count.
+                    Ok((part, attempt)) if batch.contains(&part) => attempts[part] = Some(attempt),
+                    Ok(_) => {}
+                    Err(_) => break,
+                }
+            }
+
+            for &part in batch {
+                if attempts[part].is_none() {
+                    tokens[part].cancel();
+                }
+            }
+        }
+
+        let report = |name, attempt| match attempt {
+            Some(attempt) => into_report(name, &N::now(), attempt),
+            None => timed_out(name, limit),
+        };
+        let [first, second] = attempts;
+        let reports = [report("part1", first), report("part2", second)];
+
+        self.record_parts(writer, parse_time, parse_profile, reports);
+    }
+
+    fn record_parse_panic<W: Write, N: BasicInstant>(&mut self, writer: W, now: &N, panic: Panic) {
+        let Panic {
+            message,
+            location,
+            input_error,
+        } = panic;
+
+        let mut report = RunReport::new(None, "parse", String::new(), now.elapsed());
+        report.status = Status::Panicked { message, location };
+        report.input_error = input_error.map(|error| *error);
+        self.record(writer, report);
+    }
+
+    fn record_parts<W: Write>(
+        &mut self,
+        mut writer: W,
+        parse_time: Duration,
+        parse_profile: Option<Profile>,
+        reports: [RunReport; 2],
+    ) {
+        for mut report in reports {
+            report.parse_time = Some(parse_time);
+            report.profile = with_parse(parse_profile.as_ref(), parse_time, report.profile);
+            self.record(&mut writer, report);
+        }
+    }
+
+    fn record<W: Write>(&mut self, writer: W, mut report: RunReport) -> &RunReport {
+        report.day = self.number;
+        if !report.is_failure() {
+            self.answers.verify(&mut report);
+        }
+
+        if !self.quiet {
+            write_report(writer, &report);
+        }
+
+        self.reports.push(report);
+        self.reports.last().unwrap()
+    }
+
+    pub fn failed(&self) -> bool {
+        self.reports.iter().any(|report| report.is_failure())
+    }
+
+    pub fn finish(self) {
+        if self.failed() {
+            process::exit(1);
+        }
+    }
+}
+
+// Runs the parts one after the other, or part2 on its own thread when `parallel`.
+fn both<R, A, B>(parallel: bool, part1: A, part2: B) -> [R; 2]
+where
+    R: Send,
+    A: FnOnce() -> R,
+    B: FnOnce() -> R + Send,
+{
+    if parallel {
+        thread::scope(|scope| {
+            let second = scope.spawn(part2);
+            [part1(), second.join().expect("part2 thread panicked")]
+        })
+    } else {
+        [part1(), part2()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use crate::instant;
+
+    use super::*;
+
+    fn day() -> Day {
+        Day {
+            number: Some(1),
+            dir: PathBuf::from("day1"),
+            answers: Answers::parse("part1 = 3066\npart2 = 100").unwrap(),
+            options: RunOptions::default(),
+            reports: vec![],
+            quiet: false,
+            parallel: false,
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let day = Day::new("day8", "does/not/exist");
+
+        assert_eq!(day.number(), Some(8));
+        assert_eq!(day.dir(), Path::new("does/not/exist"));
+        assert_eq!(day.answers(), &Answers::default());
+        assert_eq!(Day::new("dayN", ".").number(), None);
+    }
+
+    #[test]
+    fn test_try_new_invalid_answers() {
+        let dir = std::env::temp_dir().join(format!("helpers-day-{}", process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(ANSWERS_FILE), "part1 = \"unterminated").unwrap();
+
+        let error = Day::try_new("day3", &dir).err().unwrap();
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(error.starts_with("Invalid answers.toml: "), "{}", error);
+    }
+
+    #[test]
+    fn test_quiet_run() {
+        instant::fake::with_fake_elapsed(Duration::from_millis(10));
+        let mut day = day().quiet();
+        let mut output = Vec::new();
+
+        day.raw_run(&mut output, instant::fake::Instant::now(), "part1", || 3066);
+
+        assert!(output.is_empty());
+        assert_eq!(day.reports().len(), 1);
+    }
+
+    #[test]
+    fn test_run_verifies_answers() {
+        instant::fake::with_fake_elapsed(Duration::from_millis(10));
+        let mut day = day();
+        let mut output = Vec::new();
+
+        day.raw_run(&mut output, instant::fake::Instant::now(), "part1", || 3066);
+        assert!(!day.failed());
+
+        day.raw_run(&mut output, instant::fake::Instant::now(), "part2", || 99);
+        assert!(day.failed());
+
+        day.raw_run(&mut output, instant::fake::Instant::now(), "part3", || 1);
+
+        let output = String::from_utf8(output).expect("Not UTF-8");
+        assert_eq!(
+            output,
+            "day1 part1: 3066 (10.00ms) ✓\n\nday1 part2: 99 (10.00ms) ✗ expected 100\n\nday1 part3: 1 (10.00ms)\n\n"
+        );
+        assert_eq!(
+            day.reports()
+                .iter()
+                .map(|report| report.status.clone())
+                .collect::<Vec<_>>(),
+            [
+                Status::Correct,
+                Status::Wrong {
+                    expected: "100".to_string()
+                },
+                Status::Unknown
+            ]
+        );
+    }
+
+    #[test]
+    fn test_run_continues_after_panic() {
+        instant::fake::with_fake_elapsed(Duration::from_millis(10));
+        let mut day = day().quiet();
+        let mut output = Vec::new();
+
+        day.raw_run(&mut output, instant::fake::Instant::now(), "part1", || {
+            "abc".parse::<u32>().unwrap()
+        });
+        day.raw_run(&mut output, instant::fake::Instant::now(), "part2", || 100);
+
+        assert!(day.failed());
+        assert!(matches!(day.reports()[0].status, Status::Panicked { .. }));
+        assert_eq!(day.reports()[1].status, Status::Correct);
+    }
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input<'a> = Vec<&'a str>;
+        type Answer = usize;
+
+        fn parse(input: &str) -> Self::Input<'_> {
+            input.lines().collect()
+        }
+
+        fn part1(input: &Self::Input<'_>) -> usize {
+            input
+                .iter()
+                .map(|line| line.parse::<usize>().unwrap())
+                .sum()
+        }
+
+        fn part2(input: &Self::Input<'_>) -> usize {
+            input.len()
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        instant::fake::with_fake_samples(&[
+            Duration::from_millis(1),
+            Duration::from_millis(20),
+            Duration::from_millis(3),
+        ]);
+        let mut day = day();
+        let mut output = Vec::new();
+
+        day.raw_solve::<Sum, _, instant::fake::Instant>(&mut output, "1000\n2000\n66");
+
+        let output = String::from_utf8(output).expect("Not UTF-8");
+        assert_eq!(
+            output,
+            "day1 part1: 3066 (parse 1.00ms, solve 20.00ms) ✓\n\nday1 part2: 3 (parse 1.00ms, solve 3.00ms) ✗ expected 100\n\n"
+        );
+        assert_eq!(day.reports()[0].parse_time, Some(Duration::from_millis(1)));
+        assert_eq!(day.reports()[1].solve_time, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_solve_parallel() {
+        let mut day = day().quiet().parallel();
+
+        day.raw_solve::<Sum, _, Instant>(Vec::new(), "1000\n2000\n66");
+
+        assert_eq!(
+            day.reports()
+                .iter()
+                .map(|report| (report.part.as_str(), report.answer.as_str()))
+                .collect::<Vec<_>>(),
+            [("part1", "3066"), ("part2", "3")]
+        );
+        assert_eq!(day.reports()[0].parse_time, day.reports()[1].parse_time);
+    }
+
+    #[test]
+    fn test_solve_parse_panic() {
+        struct Broken;
+
+        impl Solution for Broken {
+            type Input<'a> = usize;
+            type Answer = usize;
+
+            fn parse(input: &str) -> usize {
+                input.parse().unwrap()
+            }
+
+            fn part1(input: &usize) -> usize {
+                *input
+            }
+
+            fn part2(input: &usize) -> usize {
+                *input
+            }
+        }
+
+        instant::fake::with_fake_elapsed(Duration::from_millis(1));
+        let mut day = day().quiet();
+
+        day.raw_solve::<Broken, _, instant::fake::Instant>(Vec::new(), "x");
+
+        assert_eq!(day.reports().len(), 1);
+        assert_eq!(day.reports()[0].part, "parse");
+        assert!(day.failed());
+    }
+
+    // Never checks its `CancelToken`, like most solvers.
+    fn stuck() -> usize {
+        loop {
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn test_solve_timeout_continues() {
+        struct Stuck;
+
+        impl Solution for Stuck {
+            type Input<'a> = Vec<&'a str>;
+            type Answer = usize;
+
+            fn parse(input: &str) -> Self::Input<'_> {
+                input.lines().collect()
+            }
+
+            fn part1(_input: &Self::Input<'_>) -> usize {
+                stuck()
+            }
+
+            fn part2(input: &Self::Input<'_>) -> usize {
+                input.len()
+            }
+        }
+
+        let mut day = day().quiet().timeout(Duration::from_millis(20));
+
+        day.raw_solve::<Stuck, _, Instant>(Vec::new(), "1000\n2000\n66");
+        day.raw_run(Vec::new(), Instant::now(), "part3", || 1);
+
+        assert_eq!(
+            day.reports()
+                .iter()
+                .map(|report| (report.part.as_str(), report.status.clone()))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    "part1",
+                    Status::TimedOut {
+                        limit: Duration::from_millis(20)
+                    }
+                ),
+                (
+                    "part2",
+                    Status::Wrong {
+                        expected: "100".to_string()
+                    }
+                ),
+                ("part3", Status::Unknown),
+            ]
+        );
+        assert!(day.failed());
+    }
+
+    #[test]
+    fn test_solve_parse_timeout() {
+        struct SlowParse;
+
+        impl Solution for SlowParse {
+            type Input<'a> = usize;
+            type Answer = usize;
+
+            fn parse(_input: &str) -> usize {
+                stuck()
+            }
+
+            fn part1(input: &usize) -> usize {
+                *input
+            }
+
+            fn part2(input: &usize) -> usize {
+                *input
+            }
+        }
+
+        let mut day = day().quiet().timeout(Duration::from_millis(20));
+
+        day.raw_solve::<SlowParse, _, Instant>(Vec::new(), "1");
+
+        assert_eq!(day.reports().len(), 1);
+        assert_eq!(day.reports()[0].part, "parse");
+        assert_eq!(
+            day.reports()[0].status,
+            Status::TimedOut {
+                limit: Duration::from_millis(20)
+            }
+        );
+    }
+
+    #[test]
+    fn test_solve_timed_releases_input() {
+        static DROPPED: AtomicBool = AtomicBool::new(false);
+
+        struct Parsed;
+
+        impl Drop for Parsed {
+            fn drop(&mut self) {
+                DROPPED.store(true, Ordering::Relaxed);
+            }
+        }
+
+        struct Owned;
+
+        impl Solution for Owned {
+            type Input<'a> = Parsed;
+            type Answer = usize;
+
+            fn parse(_input: &str) -> Parsed {
+                Parsed
+            }
+
+            fn part1(_input: &Parsed) -> usize {
+                1
+            }
+
+            fn part2(_input: &Parsed) -> usize {
+                2
+            }
+        }
+
+        let mut day = day().quiet().timeout(Duration::from_secs(5));
+        day.raw_solve::<Owned, _, Instant>(Vec::new(), "");
+        assert_eq!(
+            day.reports()
+                .iter()
+                .map(|report| report.answer.as_str())
+                .collect::<Vec<_>>(),
+            ["1", "2"]
+        );
+
+        // The worker exits shortly after sending the last part's answer.
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !DROPPED.load(Ordering::Relaxed) && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(1));
+        }
+        assert!(DROPPED.load(Ordering::Relaxed));
+    }
+}
@@ -1,5 +1,8 @@
 use std::fmt::{Debug, Formatter};
-use std::iter::FromIterator;
+use std::iter::{FromIterator, StepBy};
+use std::slice::{ChunksExact, ChunksExactMut, Iter};
+
+use crate::Point;
 
 pub type Coord = (usize, usize);
 
@@ -25,15 +28,31 @@ impl<T> Grid<T> {
     }
 
     pub fn maybe_get(&self, coord: (isize, isize)) -> Option<&T> {
-        if coord.0 < 0
-            || coord.1 < 0
-            || coord.0 >= self.size.0 as isize
-            || coord.1 >= self.size.1 as isize
-        {
+        self.at(Point::new(coord.0, coord.1))
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point
+            .to_coord()
+            .is_some_and(|coord| coord.0 < self.size.0 && coord.1 < self.size.1)
+    }
+
+    /// The cell at `point`, or `None` when it's off the grid.
+    pub fn at(&self, point: Point) -> Option<&T> {
+        if !self.contains(point) {
+            return None;
+        }
+
+        Some(&self.cells[self.index((point.x as usize, point.y as usize))])
+    }
+
+    pub fn at_mut(&mut self, point: Point) -> Option<&mut T> {
+        if !self.contains(point) {
             return None;
         }
 
-        Some(&self.cells[self.index((coord.0 as usize, coord.1 as usize))])
+        let index = self.index((point.x as usize, point.y as usize));
+        Some(&mut self.cells[index])
     }
 
     pub fn get_mut(&mut self, coord: Coord) -> &mut T {
@@ -66,6 +85,54 @@ impl<T> Grid<T> {
         )
     }
 
+    /// The cells of row `y`, left to right.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.size.1);
+
+        &self.cells[y * self.size.0..(y + 1) * self.size.0]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        assert!(y < self.size.1);
+
+        &mut self.cells[y * self.size.0..(y + 1) * self.size.0]
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn col(&self, x: usize) -> StepBy<Iter<'_, T>> {
+        assert!(x < self.size.0);
+
+        self.cells[x..].iter().step_by(self.size.0)
+    }
+
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        self.cells.chunks_exact(self.size.0.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> ChunksExactMut<'_, T> {
+        self.cells.chunks_exact_mut(self.size.0.max(1))
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = StepBy<Iter<'_, T>>> + '_ {
+        (0..self.size.0).map(move |x| self.col(x))
+    }
+
+    pub fn any_in_row(&self, y: usize, predicate: impl FnMut(&T) -> bool) -> bool {
+        self.row(y).iter().any(predicate)
+    }
+
+    pub fn all_in_row(&self, y: usize, predicate: impl FnMut(&T) -> bool) -> bool {
+        self.row(y).iter().all(predicate)
+    }
+
+    pub fn any_in_col(&self, x: usize, predicate: impl FnMut(&T) -> bool) -> bool {
+        self.col(x).any(predicate)
+    }
+
+    pub fn all_in_col(&self, x: usize, predicate: impl FnMut(&T) -> bool) -> bool {
+        self.col(x).all(predicate)
+    }
+
     pub fn neighbors_iter(&self, coord: Coord, with_diagonals: bool) -> NeighborIter<T> {
         assert!(coord.0 < self.size.0);
         assert!(coord.1 < self.size.1);
@@ -80,6 +147,78 @@ impl<T> Grid<T> {
         coord.0 + coord.1 * self.size.0
     }
 
+    pub fn flip_horizontal_in_place(&mut self) {
+        self.rows_mut().for_each(|row| row.reverse());
+    }
+
+    pub fn flip_vertical_in_place(&mut self) {
+        let (width, height) = self.size;
+
+        for y in 0..height / 2 {
+            let (top, bottom) = self.cells.split_at_mut((height - 1 - y) * width);
+            top[y * width..(y + 1) * width].swap_with_slice(&mut bottom[..width]);
+        }
+    }
+
+    /// Panics if the grid isn't square.
+    pub fn transpose_in_place(&mut self) {
+        assert_eq!(self.size.0, self.size.1, "grid must be square");
+
+        for y in 0..self.size.1 {
+            for x in y + 1..self.size.0 {
+                let (a, b) = (self.index((x, y)), self.index((y, x)));
+                self.cells.swap(a, b);
+            }
+        }
+    }
+
+    /// Panics if the grid isn't square.
+    pub fn rotate_cw_in_place(&mut self) {
+        self.transpose_in_place();
+        self.flip_horizontal_in_place();
+    }
+
+    /// Panics if the grid isn't square.
+    pub fn rotate_ccw_in_place(&mut self) {
+        self.transpose_in_place();
+        self.flip_vertical_in_place();
+    }
+
+    /// Removes row `y`, returning its cells. The width is kept once the last row is gone.
+    pub fn remove_row(&mut self, y: usize) -> Vec<T> {
+        assert!(y < self.size.1);
+
+        let removed = self
+            .cells
+            .drain(y * self.size.0..(y + 1) * self.size.0)
+            .collect();
+        self.size.1 -= 1;
+
+        removed
+    }
+
+    /// Removes column `x`, returning its cells. The height is kept once the last column is gone.
+    pub fn remove_col(&mut self, x: usize) -> Vec<T> {
+        assert!(x < self.size.0);
+
+        let width = self.size.0;
+        let mut removed = Vec::with_capacity(self.size.1);
+        let mut kept = Vec::with_capacity(self.cells.len() - self.size.1);
+
+        for (index, cell) in std::mem::take(&mut self.cells).into_iter().enumerate() {
+            if index % width == x {
+                removed.push(cell);
+            } else {
+                kept.push(cell);
+            }
+        }
+
+        self.cells = kept;
+        self.size.0 -= 1;
+
+        removed
+    }
+
     fn coord(&self, index: usize) -> Coord {
         Self::_coord(self.size, index)
     }
@@ -89,6 +228,99 @@ impl<T> Grid<T> {
     }
 }
 
+impl<T: Clone> Grid<T> {
+    pub fn transpose(&self) -> Self {
+        self.remap((self.size.1, self.size.0), |(x, y)| (y, x))
+    }
+
+    pub fn rotate_cw(&self) -> Self {
+        let height = self.size.1;
+        self.remap((self.size.1, self.size.0), |(x, y)| (y, height - 1 - x))
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        let width = self.size.0;
+        self.remap((self.size.1, self.size.0), |(x, y)| (width - 1 - y, x))
+    }
+
+    pub fn flip_horizontal(&self) -> Self {
+        let width = self.size.0;
+        self.remap(self.size, |(x, y)| (width - 1 - x, y))
+    }
+
+    pub fn flip_vertical(&self) -> Self {
+        let height = self.size.1;
+        self.remap(self.size, |(x, y)| (x, height - 1 - y))
+    }
+
+    /// Inserts a row filled with `value` before row `y`, or at the bottom when `y` is the height.
+    pub fn insert_row(&mut self, y: usize, value: T) {
+        assert!(y <= self.size.1);
+        assert!(self.size.0 > 0, "grid has no columns");
+
+        let start = y * self.size.0;
+        self.cells
+            .splice(start..start, std::iter::repeat_n(value, self.size.0));
+        self.size.1 += 1;
+    }
+
+    /// Inserts a column filled with `value` before column `x`, or at the right when `x` is the width.
+    pub fn insert_col(&mut self, x: usize, value: T) {
+        assert!(x <= self.size.0);
+        assert!(self.size.1 > 0, "grid has no rows");
+
+        let width = self.size.0;
+        let mut cells = Vec::with_capacity(self.cells.len() + self.size.1);
+
+        for row in 0..self.size.1 {
+            cells.extend_from_slice(&self.cells[row * width..row * width + x]);
+            cells.push(value.clone());
+            cells.extend_from_slice(&self.cells[row * width + x..(row + 1) * width]);
+        }
+
+        self.cells = cells;
+        self.size.0 += 1;
+    }
+
+    /// Inserts `count` copies of row `y` right after it.
+    pub fn repeat_row(&mut self, y: usize, count: usize) {
+        let row = self.row(y).to_vec();
+        let end = (y + 1) * self.size.0;
+
+        self.cells.splice(
+            end..end,
+            row.iter().cycle().take(row.len() * count).cloned(),
+        );
+        self.size.1 += count;
+    }
+
+    /// Surrounds the grid with `border` rows and columns of `value` on every side.
+    pub fn pad(&mut self, border: usize, value: T) {
+        let size = (self.size.0 + 2 * border, self.size.1 + 2 * border);
+        let mut cells = Vec::with_capacity(size.0 * size.1);
+
+        cells.extend(std::iter::repeat_n(value.clone(), size.0 * border));
+        for row in self.rows() {
+            cells.extend(std::iter::repeat_n(value.clone(), border));
+            cells.extend_from_slice(row);
+            cells.extend(std::iter::repeat_n(value.clone(), border));
+        }
+        cells.extend(std::iter::repeat_n(value, size.0 * border));
+
+        self.size = size;
+        self.cells = cells;
+    }
+
+    // Builds a grid of `size` where each cell is copied from the source coord `from` gives for it.
+    fn remap(&self, size: (usize, usize), from: impl Fn(Coord) -> Coord) -> Self {
+        let cells = (0..size.0 * size.1)
+            .map(|index| self.get(from(Self::_coord(size, index))).clone())
+            .collect();
+
+        Grid::new(size, cells)
+    }
+}
+
 impl<T: Debug> Debug for Grid<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(f)?;
@@ -241,6 +473,8 @@ impl<'a, T> Iterator for NeighborIter<'a, T> {
 
 #[cfg(test)]
 mod tests {
+    use crate::Direction;
+
     use super::*;
 
     fn create_grid() -> Grid<char> {
@@ -283,6 +517,22 @@ mod tests {
         assert_eq!(grid.get((2, 3)), &'x');
     }
 
+    #[test]
+    fn test_at() {
+        let mut grid = create_grid();
+
+        assert_eq!(grid.at(Point::new(2, 3)), Some(&'o'));
+        assert_eq!(grid.at(Point::new(2, 3) + Direction::North), Some(&'k'));
+        assert_eq!(grid.at(Point::new(-1, 0)), None);
+        assert_eq!(grid.at(Point::new(4, 0)), None);
+        assert_eq!(grid.at(Point::new(0, 5)), None);
+        assert_eq!(grid.maybe_get((3, -1)), None);
+
+        *grid.at_mut(Point::from((3, 4))).unwrap() = 'x';
+        assert_eq!(grid.get((3, 4)), &'x');
+        assert_eq!(grid.at_mut(Point::new(3, 5)), None);
+    }
+
     #[test]
     fn test_set() {
         let mut grid = create_grid();
@@ -335,6 +585,186 @@ mod tests {
         assert_eq!(grid.get((0, 0)), &'x');
     }
 
+    #[test]
+    fn test_rows_and_cols() {
+        let mut grid = create_grid();
+
+        assert_eq!(grid.row(1), ['e', 'f', 'g', 'h']);
+        assert_eq!(grid.col(2).collect::<String>(), "cgkos");
+        assert_eq!(
+            grid.rows()
+                .map(|row| row.iter().collect())
+                .collect::<Vec<String>>(),
+            ["abcd", "efgh", "ijkl", "mnop", "qrst"]
+        );
+        assert_eq!(
+            grid.cols()
+                .map(|col| col.collect())
+                .collect::<Vec<String>>(),
+            ["aeimq", "bfjnr", "cgkos", "dhlpt"]
+        );
+
+        assert!(grid.any_in_row(2, |&cell| cell == 'k'));
+        assert!(!grid.all_in_row(2, |&cell| cell == 'k'));
+        assert!(grid.all_in_col(3, |&cell| cell > 'c'));
+        assert!(!grid.any_in_col(0, |&cell| cell == 'b'));
+
+        grid.row_mut(4)[0] = 'x';
+        grid.rows_mut().for_each(|row| row[3] = 'y');
+        assert_eq!(grid.get((0, 4)), &'x');
+        assert_eq!(grid.col(3).collect::<String>(), "yyyyy");
+    }
+
+    fn rows(grid: &Grid<char>) -> Vec<String> {
+        grid.rows().map(|row| row.iter().collect()).collect()
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = create_grid();
+
+        let transposed = grid.transpose();
+        assert_eq!(transposed.size, (5, 4));
+        assert_eq!(rows(&transposed), ["aeimq", "bfjnr", "cgkos", "dhlpt"]);
+
+        let rotated = grid.rotate_cw();
+        assert_eq!(rotated.size, (5, 4));
+        assert_eq!(rows(&rotated), ["qmiea", "rnjfb", "sokgc", "tplhd"]);
+        assert_eq!(rotated.rotate_ccw().size, grid.size);
+        assert_eq!(rotated.rotate_ccw(), grid);
+
+        assert_eq!(
+            rows(&grid.rotate_ccw()),
+            ["dhlpt", "cgkos", "bfjnr", "aeimq"]
+        );
+        assert_eq!(
+            rows(&grid.flip_horizontal()),
+            ["dcba", "hgfe", "lkji", "ponm", "tsrq"]
+        );
+        assert_eq!(
+            rows(&grid.flip_vertical()),
+            ["qrst", "mnop", "ijkl", "efgh", "abcd"]
+        );
+    }
+
+    #[test]
+    fn test_transforms_in_place() {
+        let mut grid = create_grid();
+
+        grid.flip_horizontal_in_place();
+        assert_eq!(grid, create_grid().flip_horizontal());
+        grid.flip_vertical_in_place();
+        assert_eq!(rows(&grid), ["tsrq", "ponm", "lkji", "hgfe", "dcba"]);
+
+        let square = Grid::new((4, 4), create_grid().cells[..16].to_vec());
+        let mut grid = square.clone();
+
+        grid.transpose_in_place();
+        assert_eq!(grid, square.transpose());
+
+        grid = square.clone();
+        grid.rotate_cw_in_place();
+        assert_eq!(grid, square.rotate_cw());
+
+        grid.rotate_ccw_in_place();
+        assert_eq!(grid, square);
+    }
+
+    #[test]
+    #[should_panic(expected = "grid must be square")]
+    fn test_transpose_in_place_not_square() {
+        create_grid().transpose_in_place();
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut grid = create_grid();
+
+        grid.insert_row(1, '.');
+        grid.insert_row(6, '#');
+        assert_eq!(grid.size, (4, 7));
+        assert_eq!(
+            rows(&grid),
+            ["abcd", "....", "efgh", "ijkl", "mnop", "qrst", "####"]
+        );
+
+        grid.insert_col(0, '<');
+        grid.insert_col(5, '>');
+        assert_eq!(grid.size, (6, 7));
+        assert_eq!(grid.row(2), ['<', 'e', 'f', 'g', 'h', '>']);
+        assert_eq!(grid.col(5).collect::<String>(), ">>>>>>>");
+
+        assert_eq!(grid.remove_col(5), ['>'; 7]);
+        assert_eq!(grid.remove_col(0), ['<'; 7]);
+        assert_eq!(grid.remove_row(6), ['#'; 4]);
+        assert_eq!(grid.remove_row(1), ['.'; 4]);
+        assert_eq!(grid.size, (4, 5));
+        assert_eq!(grid, create_grid());
+
+        assert_eq!(grid.remove_col(1), ['b', 'f', 'j', 'n', 'r']);
+        assert_eq!(rows(&grid), ["acd", "egh", "ikl", "mop", "qst"]);
+    }
+
+    #[test]
+    fn test_remove_all_and_insert() {
+        let mut grid = create_grid();
+        while grid.size.1 > 0 {
+            grid.remove_row(0);
+        }
+        assert_eq!(grid.size, (4, 0));
+        assert_eq!(grid.len(), 0);
+        assert_eq!(grid.iter().count(), 0);
+        assert_eq!(grid.rows().count(), 0);
+
+        grid.insert_row(0, 'x');
+        grid.insert_row(1, 'y');
+        assert_eq!(grid.size, (4, 2));
+        assert_eq!(rows(&grid), ["xxxx", "yyyy"]);
+
+        let mut grid = create_grid();
+        while grid.size.0 > 0 {
+            grid.remove_col(0);
+        }
+        assert_eq!(grid.size, (0, 5));
+        assert_eq!(grid.len(), 0);
+        assert_eq!(grid.iter().count(), 0);
+
+        grid.insert_col(0, 'x');
+        assert_eq!(grid.size, (1, 5));
+        assert_eq!(rows(&grid), ["x", "x", "x", "x", "x"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "grid has no columns")]
+    fn test_insert_row_without_columns() {
+        let mut grid = create_grid();
+        for _ in 0..4 {
+            grid.remove_col(0);
+        }
+        grid.insert_row(0, '.');
+    }
+
+    #[test]
+    fn test_repeat_row_and_pad() {
+        let mut grid = create_grid();
+
+        grid.repeat_row(1, 2);
+        assert_eq!(grid.size, (4, 7));
+        assert_eq!(
+            rows(&grid),
+            ["abcd", "efgh", "efgh", "efgh", "ijkl", "mnop", "qrst"]
+        );
+
+        let mut grid = create_grid();
+        grid.pad(1, '.');
+        assert_eq!(grid.size, (6, 7));
+        assert_eq!(
+            rows(&grid),
+            ["......", ".abcd.", ".efgh.", ".ijkl.", ".mnop.", ".qrst.", "......"]
+        );
+        assert_eq!(grid.get((1, 1)), &'a');
+    }
+
     #[test]
     fn test_iter_cell_neighbors() {
         let grid = create_grid();
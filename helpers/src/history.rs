@@ -0,0 +1,342 @@
+use std::fmt::{Display, Formatter};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{fs, io};
+
+use crate::RunReport;
+
+pub const HISTORY_FILE: &str = "timings.log";
+pub const DEFAULT_REGRESSION_THRESHOLD: f64 = 10.0;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub time: u64,
+    pub day: u8,
+    pub part: String,
+    pub input: u64,
+    /// `debug` or `release`, runs are only compared with runs of the same build.
+    pub build: String,
+    /// Whether other parts were solved at the same time, contending for the cores.
+    pub parallel: bool,
+    pub parse: Option<Duration>,
+    pub solve: Duration,
+}
+
+#[derive(Debug, Default)]
+pub struct History {
+    path: PathBuf,
+    entries: Vec<Timing>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Regression {
+    pub best: Duration,
+    pub current: Duration,
+}
+
+const UNKNOWN_BUILD: &str = "unknown";
+
+/// The build profile of this binary, as recorded in the history.
+pub fn build_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+/// FNV-1a, stable across builds unlike the std hasher, so history entries stay comparable.
+pub fn input_hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+pub(crate) fn unix_time(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+impl Timing {
+    /// Only runs of a known day that produced an answer are worth comparing.
+    pub fn from_report(
+        report: &RunReport,
+        input: u64,
+        parallel: bool,
+        time: SystemTime,
+    ) -> Option<Self> {
+        if report.is_failure() {
+            return None;
+        }
+
+        Some(Timing {
+            time: unix_time(time),
+            day: report.day?,
+            part: report.part.clone(),
+            input,
+            build: build_profile().to_string(),
+            parallel,
+            parse: report.parse_time,
+            solve: report.solve_time,
+        })
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse.unwrap_or_default() + self.solve
+    }
+
+    fn same_run(&self, other: &Timing) -> bool {
+        self.day == other.day
+            && self.part == other.part
+            && self.input == other.input
+            && self.build == other.build
+            && self.parallel == other.parallel
+    }
+}
+
+impl History {
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(error),
+        };
+
+        let entries = content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.parse()
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(History { path, entries })
+    }
+
+    pub fn entries(&self) -> &[Timing] {
+        &self.entries
+    }
+
+    pub fn append(&mut self, timing: Timing) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", timing)?;
+
+        self.entries.push(timing);
+        Ok(())
+    }
+
+    pub fn best(&self, timing: &Timing) -> Option<Duration> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.same_run(timing))
+            .map(|entry| entry.total())
+            .min()
+    }
+
+    /// Compares `timing` with the best recorded run, `threshold` is the allowed slowdown in percent.
+    pub fn check(&self, timing: &Timing, threshold: f64) -> Option<Regression> {
+        let best = self.best(timing)?;
+        let regression = Regression {
+            best,
+            current: timing.total(),
+        };
+
+        (regression.percent() > threshold).then_some(regression)
+    }
+}
+
+impl Regression {
+    pub fn percent(&self) -> f64 {
+        if self.best.is_zero() {
+            return 0.0;
+        }
+
+        (self.current.as_secs_f64() / self.best.as_secs_f64() - 1.0) * 100.0
+    }
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "+{:.0}% slower than best {:.2?}",
+            self.percent(),
+            self.best
+        )
+    }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{:016x}\t{}\t{}\t{}\t{}",
+            self.time,
+            self.day,
+            self.part,
+            self.input,
+            self.build,
+            if self.parallel {
+                "parallel"
+            } else {
+                "sequential"
+            },
+            self.parse
+                .map_or("-".to_string(), |time| time.as_nanos().to_string()),
+            self.solve.as_nanos()
+        )
+    }
+}
+
+impl FromStr for Timing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || format!("Invalid timing entry `{}`", s);
+        let mut fields: Vec<&str> = s.split('\t').collect();
+
+        // Entries written before the build and scheduling were recorded never match a new run.
+        if fields.len() == 6 {
+            fields.splice(4..4, [UNKNOWN_BUILD, "sequential"]);
+        }
+        if fields.len() != 8 {
+            return Err(error());
+        }
+
+        let nanos = |field: &str| field.parse().map(Duration::from_nanos).map_err(|_| error());
+
+        Ok(Timing {
+            time: fields[0].parse().map_err(|_| error())?,
+            day: fields[1].parse().map_err(|_| error())?,
+            part: fields[2].to_string(),
+            input: u64::from_str_radix(fields[3], 16).map_err(|_| error())?,
+            build: fields[4].to_string(),
+            parallel: match fields[5] {
+                "parallel" => true,
+                "sequential" => false,
+                _ => return Err(error()),
+            },
+            parse: match fields[6] {
+                "-" => None,
+                parse => Some(nanos(parse)?),
+            },
+            solve: nanos(fields[7])?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use crate::Status;
+
+    use super::*;
+
+    fn timing(part: &str, input: u64, solve_millis: u64) -> Timing {
+        Timing {
+            time: 1701417600,
+            day: 3,
+            part: part.to_string(),
+            input,
+            build: "release".to_string(),
+            parallel: false,
+            parse: Some(Duration::from_millis(1)),
+            solve: Duration::from_millis(solve_millis),
+        }
+    }
+
+    #[test]
+    fn test_timing_round_trip() {
+        let timing = timing("part1", 0xabc, 10);
+
+        assert_eq!(
+            timing.to_string(),
+            "1701417600\t3\tpart1\t0000000000000abc\trelease\tsequential\t1000000\t10000000"
+        );
+        assert_eq!(timing.to_string().parse(), Ok(timing));
+        assert!("1701417600\t3\tpart1".parse::<Timing>().is_err());
+
+        let old: Timing = "1701417600\t3\tpart1\t0000000000000abc\t1000000\t10000000"
+            .parse()
+            .unwrap();
+        assert_eq!(old.build, "unknown");
+        assert_eq!(old.solve, Duration::from_millis(10));
+    }
+
+    #[test]
+    fn test_from_report() {
+        let mut report =
+            RunReport::new(Some(3), "part2", "7".to_string(), Duration::from_millis(2));
+        let time = UNIX_EPOCH + Duration::from_secs(1701417600);
+
+        assert_eq!(
+            Timing::from_report(&report, 1, true, time),
+            Some(Timing {
+                time: 1701417600,
+                day: 3,
+                part: "part2".to_string(),
+                input: 1,
+                build: build_profile().to_string(),
+                parallel: true,
+                parse: None,
+                solve: Duration::from_millis(2),
+            })
+        );
+
+        report.status = Status::Wrong {
+            expected: "8".to_string(),
+        };
+        assert_eq!(Timing::from_report(&report, 1, false, time), None);
+    }
+
+    #[test]
+    fn test_check_against_best() {
+        let path = env::temp_dir().join(format!("helpers-history-{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut history = History::load(&path).unwrap();
+        assert_eq!(history.check(&timing("part1", 1, 10), 10.0), None);
+
+        history.append(timing("part1", 1, 10)).unwrap();
+        history.append(timing("part1", 1, 8)).unwrap();
+        history.append(timing("part1", 2, 1)).unwrap();
+        history.append(timing("part2", 1, 1)).unwrap();
+
+        let history = History::load(&path).unwrap();
+        assert_eq!(history.entries().len(), 4);
+        assert_eq!(history.check(&timing("part1", 1, 9), 20.0), None);
+
+        let regression = history.check(&timing("part1", 1, 17), 20.0).unwrap();
+        assert_eq!(regression.best, Duration::from_millis(9));
+        assert_eq!(regression.to_string(), "+100% slower than best 9.00ms");
+
+        let debug = Timing {
+            build: "debug".to_string(),
+            ..timing("part1", 1, 170)
+        };
+        assert_eq!(history.check(&debug, 20.0), None);
+
+        let parallel = Timing {
+            parallel: true,
+            ..timing("part1", 1, 170)
+        };
+        assert_eq!(history.check(&parallel, 20.0), None);
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), 0xcbf29ce484222325);
+        assert_eq!(input_hash("a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(input_hash("1\n2"), input_hash("2\n1"));
+    }
+}
@@ -1,8 +1,138 @@
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter};
+use std::io::Read;
 use std::iter::FromIterator;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::{env, fs, io};
+
+use crate::{Coord, Grid, InputError};
+
+pub const INPUT_FILE: &str = "input.txt";
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+#[cfg(feature = "embed")]
+#[macro_export]
+macro_rules! input {
+    () => {
+        String::from(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/input.txt"
+        )))
+    };
+}
+
+#[cfg(not(feature = "embed"))]
+#[macro_export]
+macro_rules! input {
+    () => {
+        $crate::load_input(env!("CARGO_MANIFEST_DIR")).unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            std::process::exit(2);
+        })
+    };
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+#[derive(Debug)]
+pub enum LoadError {
+    MissingPath,
+    NotFound(PathBuf),
+    Io(InputSource, io::Error),
+}
+
+impl InputSource {
+    pub fn resolve<P: AsRef<Path>>(
+        args: &[String],
+        env_value: Option<String>,
+        day_dir: P,
+    ) -> Result<Self, LoadError> {
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            if arg == "--input" {
+                return args
+                    .next()
+                    .map(|value| Self::from_value(value))
+                    .ok_or(LoadError::MissingPath);
+            }
+
+            if let Some(value) = arg.strip_prefix("--input=") {
+                return Ok(Self::from_value(value));
+            }
+        }
+
+        if let Some(value) = env_value.filter(|value| !value.is_empty()) {
+            return Ok(Self::from_value(&value));
+        }
 
-use crate::Grid;
+        let path = day_dir.as_ref().join(INPUT_FILE);
+        if path.is_file() {
+            Ok(InputSource::File(path))
+        } else {
+            Err(LoadError::NotFound(path))
+        }
+    }
+
+    pub fn read(&self) -> Result<String, LoadError> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|error| LoadError::Io(self.clone(), error))?;
+                Ok(input)
+            }
+            InputSource::File(path) => {
+                fs::read_to_string(path).map_err(|error| LoadError::Io(self.clone(), error))
+            }
+        }
+    }
+
+    fn from_value(value: &str) -> Self {
+        if value == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(value))
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::MissingPath => write!(f, "Missing path after --input (use `-` for stdin)"),
+            LoadError::NotFound(path) => write!(
+                f,
+                "No input found: pass --input <path>, use `--input -` for stdin, set {} or create {}",
+                INPUT_ENV,
+                path.display()
+            ),
+            LoadError::Io(source, error) => write!(f, "Could not read input from {}: {}", source, error),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+pub fn load_input<P: AsRef<Path>>(day_dir: P) -> Result<String, LoadError> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    InputSource::resolve(&args, env::var(INPUT_ENV).ok(), day_dir)?.read()
+}
 
 pub fn input_lines<'a, R>(input: &'a str) -> R
 where
@@ -32,61 +162,274 @@ where
         .collect()
 }
 
+#[track_caller]
 pub fn input_grid<R>(input: &str) -> Grid<R>
 where
     R: FromStr + Clone,
     <R as FromStr>::Err: Debug,
 {
-    let lines: Vec<&str> = input
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| false == line.is_empty())
-        .collect();
-
-    let size = (lines[0].len(), lines.len());
-    let mut data: Vec<R> = Vec::with_capacity(size.0 * size.1);
-
-    for line in lines {
-        for element in line.split("").filter(|&x| !x.is_empty()) {
-            data.push(element.parse().unwrap());
-        }
-    }
+    raise_on_error(parse_grid(input, |error| format!("{:?}", error)))
+}
 
-    Grid::new(size, data)
+pub fn try_input_grid<R>(input: &str) -> Result<Grid<R>, InputError>
+where
+    R: FromStr + Clone,
+    <R as FromStr>::Err: Display,
+{
+    parse_grid(input, ToString::to_string)
 }
 
+#[track_caller]
 pub fn parse_split_input<T: FromStr, R>(input: &str, pattern: &str) -> R
 where
     T::Err: Debug,
     R: FromIterator<T>,
 {
-    input
-        .split(pattern)
-        .map(|line| line.trim())
-        .filter(|line| false == line.is_empty())
-        .map(|line| line.parse().unwrap())
-        .collect()
+    raise_on_error(parse_tokens(
+        input,
+        split_input::<Vec<_>>(input, pattern),
+        |error| format!("{:?}", error),
+    ))
+}
+
+pub fn try_parse_split_input<T: FromStr, R>(input: &str, pattern: &str) -> Result<R, InputError>
+where
+    T::Err: Display,
+    R: FromIterator<T>,
+{
+    parse_tokens(
+        input,
+        split_input::<Vec<_>>(input, pattern),
+        ToString::to_string,
+    )
 }
 
+#[track_caller]
 pub fn parse_input<T: FromStr, R>(input: &str) -> R
 where
     T::Err: Debug,
     R: FromIterator<T>,
 {
-    input
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| false == line.is_empty())
-        .map(|line| line.parse().unwrap())
+    raise_on_error(parse_tokens(input, input_lines::<Vec<_>>(input), |error| {
+        format!("{:?}", error)
+    }))
+}
+
+pub fn try_parse_input<T: FromStr, R>(input: &str) -> Result<R, InputError>
+where
+    T::Err: Display,
+    R: FromIterator<T>,
+{
+    parse_tokens(input, input_lines::<Vec<_>>(input), ToString::to_string)
+}
+
+#[track_caller]
+fn raise_on_error<R>(result: Result<R, InputError>) -> R {
+    match result {
+        Ok(result) => result,
+        Err(error) => error.raise(),
+    }
+}
+
+fn parse_tokens<T: FromStr, R>(
+    input: &str,
+    tokens: Vec<&str>,
+    reason: impl Fn(&T::Err) -> String,
+) -> Result<R, InputError>
+where
+    R: FromIterator<T>,
+{
+    tokens
+        .into_iter()
+        .map(|token| {
+            token
+                .parse()
+                .map_err(|error| InputError::at::<T>(input, token, reason(&error)))
+        })
         .collect()
 }
 
+fn parse_grid<R>(input: &str, reason: impl Fn(&R::Err) -> String) -> Result<Grid<R>, InputError>
+where
+    R: FromStr + Clone,
+{
+    let lines: Vec<&str> = input_lines(input);
+    let width = lines.first().map_or(0, |line| line.chars().count());
+    check_rows(input, &lines, width, |line| line.chars().count())?;
+
+    let mut data: Vec<R> = Vec::with_capacity(width * lines.len());
+
+    for line in &lines {
+        for (index, char) in line.char_indices() {
+            let element = &line[index..index + char.len_utf8()];
+            let value = element
+                .parse()
+                .map_err(|error| InputError::at::<R>(input, element, reason(&error)))?;
+            data.push(value);
+        }
+    }
+
+    Ok(Grid::new((width, lines.len()), data))
+}
+
+#[track_caller]
+pub fn input_grid_with<T, F>(input: &str, cell: F) -> Grid<T>
+where
+    F: FnMut(u8, Coord) -> T,
+{
+    raise_on_error(try_input_grid_with(input, cell))
+}
+
+/// Builds a grid by mapping every byte of the input, e.g. `|byte, _| byte == b'#'`.
+pub fn try_input_grid_with<T, F>(input: &str, mut cell: F) -> Result<Grid<T>, InputError>
+where
+    F: FnMut(u8, Coord) -> T,
+{
+    let lines: Vec<&str> = input_lines(input);
+    let width = lines.first().map_or(0, |line| line.len());
+    check_rows(input, &lines, width, str::len)?;
+
+    let mut cells = Vec::with_capacity(width * lines.len());
+    for (y, line) in lines.iter().enumerate() {
+        cells.extend(line.bytes().enumerate().map(|(x, byte)| cell(byte, (x, y))));
+    }
+
+    Ok(Grid::new((width, lines.len()), cells))
+}
+
+#[track_caller]
+pub fn input_byte_grid(input: &str) -> Grid<u8> {
+    raise_on_error(try_input_byte_grid(input))
+}
+
+pub fn try_input_byte_grid(input: &str) -> Result<Grid<u8>, InputError> {
+    let lines: Vec<&str> = input_lines(input);
+    let width = lines.first().map_or(0, |line| line.len());
+    check_rows(input, &lines, width, str::len)?;
+
+    let mut cells = Vec::with_capacity(width * lines.len());
+    for line in &lines {
+        cells.extend_from_slice(line.as_bytes());
+    }
+
+    Ok(Grid::new((width, lines.len()), cells))
+}
+
+fn check_rows(
+    input: &str,
+    lines: &[&str],
+    width: usize,
+    len: impl Fn(&str) -> usize,
+) -> Result<(), InputError> {
+    match lines.iter().find(|line| len(line) != width) {
+        Some(line) => Err(InputError::new(
+            input,
+            line,
+            "grid row".to_string(),
+            format!(
+                "expected {} cells like the first row, found {}",
+                width,
+                len(line)
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
 
     use super::*;
 
+    fn temp_day_dir(name: &str, input: Option<&str>) -> PathBuf {
+        let dir = env::temp_dir().join(format!("helpers-input-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        match input {
+            Some(input) => fs::write(dir.join(INPUT_FILE), input).unwrap(),
+            None => {
+                let _ = fs::remove_file(dir.join(INPUT_FILE));
+            }
+        }
+
+        dir
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_resolve_input_argument() {
+        let dir = temp_day_dir("argument", Some("default"));
+
+        assert_eq!(
+            InputSource::resolve(
+                &args(&["--input", "other.txt"]),
+                Some("env.txt".into()),
+                &dir
+            )
+            .unwrap(),
+            InputSource::File(PathBuf::from("other.txt"))
+        );
+        assert_eq!(
+            InputSource::resolve(&args(&["--input=-"]), None, &dir).unwrap(),
+            InputSource::Stdin
+        );
+        assert!(matches!(
+            InputSource::resolve(&args(&["--input"]), None, &dir),
+            Err(LoadError::MissingPath)
+        ));
+    }
+
+    #[test]
+    fn test_resolve_input_env() {
+        let dir = temp_day_dir("env", Some("default"));
+
+        assert_eq!(
+            InputSource::resolve(&[], Some("env.txt".into()), &dir).unwrap(),
+            InputSource::File(PathBuf::from("env.txt"))
+        );
+        assert_eq!(
+            InputSource::resolve(&[], Some("-".into()), &dir).unwrap(),
+            InputSource::Stdin
+        );
+    }
+
+    #[test]
+    fn test_resolve_default_input() {
+        let dir = temp_day_dir("default", Some("1\n2\n"));
+
+        let source = InputSource::resolve(&[], Some("".into()), &dir).unwrap();
+
+        assert_eq!(source, InputSource::File(dir.join(INPUT_FILE)));
+        assert_eq!(source.read().unwrap(), "1\n2\n");
+    }
+
+    #[test]
+    fn test_resolve_missing_input() {
+        let dir = temp_day_dir("missing", None);
+
+        let error = InputSource::resolve(&[], None, &dir).unwrap_err();
+
+        assert!(matches!(&error, LoadError::NotFound(path) if path == &dir.join(INPUT_FILE)));
+        assert!(error
+            .to_string()
+            .starts_with("No input found: pass --input <path>"));
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let error = InputSource::File(PathBuf::from("does/not/exist.txt"))
+            .read()
+            .unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with("Could not read input from does/not/exist.txt: "));
+    }
+
     #[test]
     fn test_input_lines_to_vec() {
         let input = "
@@ -207,6 +550,52 @@ qrst
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_input_grid_empty() {
+        let result: Grid<char> = input_grid("\n");
+
+        assert_eq!(result.size, (0, 0));
+        assert_eq!(result.len(), 0);
+    }
+
+    #[test]
+    fn test_input_grid_with() {
+        let input = "
+#.#
+..#
+";
+
+        let result = input_grid_with(input, |byte, _| byte == b'#');
+        let coords = input_grid_with(input, |_, coord| coord);
+
+        assert_eq!(
+            result,
+            Grid::new((3, 2), vec![true, false, true, false, false, true])
+        );
+        assert_eq!(coords.get((2, 1)), &(2, 1));
+    }
+
+    #[test]
+    fn test_input_byte_grid() {
+        let result = input_byte_grid("ab\ncd\n");
+
+        assert_eq!(result.size, (2, 2));
+        assert_eq!(result.get((1, 1)), &b'd');
+    }
+
+    #[test]
+    fn test_ragged_grid() {
+        let input = "abc\nde\nfgh\n";
+
+        let error = try_input_byte_grid(input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "de");
+        assert_eq!(error.reason, "expected 3 cells like the first row, found 2");
+
+        assert_eq!(try_input_grid::<char>(input).unwrap_err(), error);
+        assert!(try_input_grid_with(input, |byte, _| byte).is_err());
+    }
+
     #[test]
     fn test_parse_input_to_vec() {
         let input = "
@@ -225,6 +614,46 @@ qrst
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_try_parse_input() {
+        let input = "
+            1721
+            979
+            36a6
+        ";
+
+        let error = try_parse_input::<u64, Vec<u64>>(input).unwrap_err();
+
+        assert_eq!((error.line, error.column), (4, 13));
+        assert_eq!(error.text, "36a6");
+        assert_eq!(error.target, "u64");
+        assert_eq!(error.reason, "invalid digit found in string");
+        assert_eq!(try_parse_input::<u64, Vec<u64>>("1\n2\n"), Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_try_parse_split_input() {
+        let error = try_parse_split_input::<u8, Vec<u8>>("1,2,\n300", ",").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "300");
+        assert_eq!(error.reason, "number too large to fit in target type");
+    }
+
+    #[test]
+    fn test_try_input_grid() {
+        let error = try_input_grid::<u8>("123\n4x6\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot parse `x` as u32")]
+    fn test_parse_input_panics_with_location() {
+        let _: Vec<u32> = parse_input("1\nx");
+    }
+
     #[test]
     fn test_parse_input_to_hashset() {
         let input = "
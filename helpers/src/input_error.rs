@@ -0,0 +1,176 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputError {
+    /// 1-based line of the input the bad text starts on, 0 when it isn't part of the input.
+    pub line: usize,
+    /// 1-based column, in characters, where the bad text starts.
+    pub column: usize,
+    pub text: String,
+    pub line_text: String,
+    pub target: String,
+    pub reason: String,
+}
+
+impl InputError {
+    /// Builds an error for `text`, a slice of `input`, failing to parse as `T`. Text from elsewhere
+    /// is reported without a location.
+    pub fn at<T>(input: &str, text: &str, reason: String) -> Self {
+        Self::new(input, text, type_name::<T>().to_string(), reason)
+    }
+
+    /// Like `at`, describing what was expected with `target` instead of a type.
+    pub fn new(input: &str, text: &str, target: String, reason: String) -> Self {
+        let Some(offset) = (text.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| offset + text.len() <= input.len())
+        else {
+            return InputError {
+                line: 0,
+                column: 0,
+                text: text.to_string(),
+                line_text: String::new(),
+                target,
+                reason,
+            };
+        };
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |index| offset + index);
+
+        InputError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.to_string(),
+            line_text: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            target,
+            reason,
+        }
+    }
+
+    /// Panics with the rendered error; parts solved by the runner report it as a failed part.
+    #[track_caller]
+    pub fn raise(self) -> ! {
+        crate::panic::raise(self)
+    }
+
+    /// Renders the error with the offending line and a caret under the bad text.
+    pub fn render(&self) -> String {
+        if self.line == 0 {
+            return format!("error: {}\n", self.message());
+        }
+
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let width = self
+            .text
+            .lines()
+            .next()
+            .map_or(0, |text| text.chars().count())
+            .max(1);
+
+        format!(
+            "error: {}\n{}--> line {}, column {}\n{} |\n{} | {}\n{} | {}{}\n",
+            self.message(),
+            gutter,
+            self.line,
+            self.column,
+            gutter,
+            number,
+            self.line_text,
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(width)
+        )
+    }
+
+    fn message(&self) -> String {
+        let mut message = format!("cannot parse `{}` as {}", self.text, self.target);
+        if !self.reason.is_empty() {
+            message.push_str(": ");
+            message.push_str(&self.reason);
+        }
+        message
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.line == 0 {
+            return write!(f, "{}", self.message());
+        }
+
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line,
+            self.column,
+            self.message()
+        )
+    }
+}
+
+impl Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let input = "12\n\n  34 x5\n";
+        let error = InputError::at::<u32>(input, &input[9..11], "invalid digit".to_string());
+
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 6);
+        assert_eq!(error.text, "x5");
+        assert_eq!(error.line_text, "  34 x5");
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 6: cannot parse `x5` as u32: invalid digit"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let input = "1721\n979\n36a6\n";
+        let error = InputError::at::<u64>(
+            input,
+            &input[9..13],
+            "invalid digit found in string".to_string(),
+        );
+
+        assert_eq!(
+            error.render(),
+            "\
+error: cannot parse `36a6` as u64: invalid digit found in string
+ --> line 3, column 1
+  |
+3 | 36a6
+  | ^^^^
+"
+        );
+    }
+
+    #[test]
+    fn test_text_outside_input() {
+        let text = "x5".to_string();
+        let error = InputError::at::<u32>("12\n34\n", &text, "invalid digit".to_string());
+
+        assert_eq!(error.line, 0);
+        assert_eq!(error.column, 0);
+        assert_eq!(error.line_text, "");
+        assert_eq!(error.to_string(), "cannot parse `x5` as u32: invalid digit");
+        assert_eq!(
+            error.render(),
+            "error: cannot parse `x5` as u32: invalid digit\n"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::time::{Duration, Instant};
 
-pub trait BasicInstant {
+pub trait BasicInstant: Send + Sync + 'static {
     fn now() -> Self;
 
     fn elapsed(&self) -> Duration;
@@ -16,17 +16,25 @@ impl BasicInstant for Instant {
     }
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "test-support"))]
 pub mod fake {
-    use std::cell::Cell;
+    use std::cell::{Cell, RefCell};
+    use std::collections::VecDeque;
     use std::time::Duration;
 
+    // Fakes are per thread, so they don't apply to parts solved on other threads.
     thread_local! {
         static FAKE_ELAPSED: Cell<Duration> = Cell::new(Duration::default());
+        static FAKE_SAMPLES: RefCell<VecDeque<Duration>> = const { RefCell::new(VecDeque::new()) };
     }
 
     pub fn with_fake_elapsed(duration: Duration) {
         FAKE_ELAPSED.with(|cell| cell.set(duration));
+        FAKE_SAMPLES.with(|samples| samples.borrow_mut().clear());
+    }
+
+    pub fn with_fake_samples(durations: &[Duration]) {
+        FAKE_SAMPLES.with(|samples| *samples.borrow_mut() = durations.iter().copied().collect());
     }
 
     #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
@@ -38,7 +46,9 @@ pub mod fake {
         }
 
         fn elapsed(&self) -> Duration {
-            FAKE_ELAPSED.with(|cell| cell.get())
+            FAKE_SAMPLES
+                .with(|samples| samples.borrow_mut().pop_front())
+                .unwrap_or_else(|| FAKE_ELAPSED.with(|cell| cell.get()))
         }
     }
 }
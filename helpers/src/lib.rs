@@ -1,8 +1,52 @@
+// Lets `helpers` paths in code generated by `helpers-derive` resolve inside this crate.
+extern crate self as helpers;
+
+pub use alloc::*;
+pub use answers::*;
+pub use bench::*;
+pub use cancel::*;
+#[cfg(feature = "online")]
+pub use client::*;
+pub use day::*;
 pub use grid::*;
+#[cfg(feature = "derive")]
+pub use helpers_derive::PuzzleParse;
+pub use history::*;
 pub use input::*;
+pub use input_error::*;
+pub use point::*;
+pub use profile::*;
+pub use puzzle::*;
+pub use report::*;
 pub use run::*;
+pub use scan::*;
+pub use search::*;
+pub use solution::*;
+#[cfg(feature = "online")]
+pub use submit::*;
 
+mod alloc;
+mod answers;
+mod bench;
+mod cancel;
+#[cfg(feature = "online")]
+mod client;
+mod day;
 mod grid;
+mod history;
 mod input;
+mod input_error;
 mod instant;
+mod panic;
+mod point;
+mod profile;
+mod puzzle;
+mod report;
 mod run;
+mod scan;
+mod search;
+mod solution;
+#[cfg(feature = "online")]
+mod submit;
+#[cfg(feature = "test-support")]
+pub mod testing;
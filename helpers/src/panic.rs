@@ -0,0 +1,118 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use crate::InputError;
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+    static INPUT_ERROR: RefCell<Option<InputError>> = const { RefCell::new(None) };
+}
+
+static HOOK: Once = Once::new();
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Panic {
+    pub message: String,
+    pub location: Option<String>,
+    pub input_error: Option<Box<InputError>>,
+}
+
+// Panics with the rendered error, keeping the error itself around for `catch` to report.
+#[track_caller]
+pub(crate) fn raise(error: InputError) -> ! {
+    let rendered = error.render();
+    INPUT_ERROR.with(|input_error| *input_error.borrow_mut() = Some(error));
+    panic!("{}", rendered.trim_end());
+}
+
+// Runs `f`, turning a panic into its message and location. The default hook stays in place for
+// panics outside of `catch`, the ones caught here are reported by the caller instead.
+pub(crate) fn catch<F, R>(f: F) -> Result<R, Panic>
+where
+    F: FnOnce() -> R,
+{
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) {
+                LOCATION.with(|location| {
+                    *location.borrow_mut() = info.location().map(|location| location.to_string())
+                });
+            } else {
+                previous(info);
+            }
+        }));
+    });
+
+    let catching = CATCHING.with(|catching| catching.replace(true));
+    INPUT_ERROR.with(|input_error| input_error.borrow_mut().take());
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|cell| cell.set(catching));
+
+    result.map_err(|payload| {
+        let input_error = INPUT_ERROR.with(|input_error| input_error.borrow_mut().take());
+
+        Panic {
+            message: match &input_error {
+                Some(error) => error.to_string(),
+                None => message(payload.as_ref()),
+            },
+            location: LOCATION.with(|location| location.borrow_mut().take()),
+            input_error: input_error.map(Box::new),
+        }
+    })
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch() {
+        assert_eq!(catch(|| 42), Ok(42));
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        let line = line!() + 1;
+        let panic = catch(|| -> u32 { panic!("bad line {}", 3) }).unwrap_err();
+
+        assert_eq!(panic.message, "bad line 3");
+        assert_eq!(panic.location, Some(format!("{}:{}:39", file!(), line)));
+    }
+
+    #[test]
+    fn test_catch_input_error() {
+        let input = "1\nx\n";
+        let error = InputError::at::<u32>(input, &input[2..3], String::new());
+
+        let panic = catch(|| -> u32 { raise(error.clone()) }).unwrap_err();
+
+        assert_eq!(panic.message, "line 2, column 1: cannot parse `x` as u32");
+        assert_eq!(panic.input_error, Some(Box::new(error)));
+    }
+
+    #[test]
+    fn test_catch_unwrap() {
+        let panic = catch(|| "x".parse::<u32>().unwrap()).unwrap_err();
+
+        assert_eq!(
+            panic.message,
+            "called `Result::unwrap()` on an `Err` value: ParseIntError { kind: InvalidDigit }"
+        );
+        assert!(panic.location.unwrap().starts_with(file!()));
+    }
+}
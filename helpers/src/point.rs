@@ -0,0 +1,232 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::Coord;
+
+/// A signed position, `y` grows downwards like the rows of a `Grid`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn neighbors(self, with_diagonals: bool) -> impl Iterator<Item = Point> {
+        let directions: &[Direction] = if with_diagonals {
+            &Direction::ALL
+        } else {
+            &Direction::CARDINAL
+        };
+
+        directions.iter().map(move |&direction| self + direction)
+    }
+
+    /// The equivalent `Coord`, or `None` if either component is negative.
+    pub fn to_coord(self) -> Option<Coord> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl Direction {
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::NorthEast => Point::new(1, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::SouthEast => Point::new(1, 1),
+            Direction::South => Point::new(0, 1),
+            Direction::SouthWest => Point::new(-1, 1),
+            Direction::West => Point::new(-1, 0),
+            Direction::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    /// Rotates clockwise by `steps` eighths of a turn, counter-clockwise when negative.
+    pub fn rotate(self, steps: isize) -> Self {
+        Direction::ALL[(self as isize + steps).rem_euclid(8) as usize]
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.rotate(-2)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl From<Coord> for Point {
+    fn from(coord: Coord) -> Self {
+        Point::new(coord.0 as isize, coord.1 as isize)
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: isize) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Direction) -> Point {
+        self + direction.offset()
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_arithmetic() {
+        let mut point = Point::new(2, 3);
+
+        assert_eq!(point + Point::new(-3, 1), Point::new(-1, 4));
+        assert_eq!(point - Point::new(2, 5), Point::new(0, -2));
+        assert_eq!(point * 3, Point::new(6, 9));
+        assert_eq!(-point, Point::new(-2, -3));
+        assert_eq!(point + Direction::North, Point::new(2, 2));
+        assert_eq!(point.manhattan(Point::new(-1, 5)), 5);
+
+        point += Direction::SouthWest;
+        assert_eq!(point, Point::new(1, 4));
+    }
+
+    #[test]
+    fn test_point_coord_interop() {
+        assert_eq!(Point::from((3, 4)), Point::new(3, 4));
+        assert_eq!(Point::new(3, 4).to_coord(), Some((3, 4)));
+        assert_eq!(Point::new(-1, 4).to_coord(), None);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let point = Point::new(1, 1);
+
+        assert_eq!(
+            point.neighbors(false).collect::<Vec<_>>(),
+            [
+                Point::new(1, 0),
+                Point::new(2, 1),
+                Point::new(1, 2),
+                Point::new(0, 1)
+            ]
+        );
+        assert_eq!(point.neighbors(true).count(), 8);
+    }
+
+    #[test]
+    fn test_direction_turns() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::East.opposite(), Direction::West);
+        assert_eq!(Direction::NorthWest.rotate(1), Direction::North);
+        assert_eq!(Direction::North.rotate(-1), Direction::NorthWest);
+        assert_eq!(Direction::South.rotate(10), Direction::West);
+        assert!(Direction::SouthEast.is_diagonal());
+        assert!(!Direction::South.is_diagonal());
+
+        for direction in Direction::ALL {
+            assert_eq!(direction.opposite().offset(), -direction.offset());
+        }
+    }
+}
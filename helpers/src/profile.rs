@@ -0,0 +1,270 @@
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    pub spans: Vec<SpanStats>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanStats {
+    pub name: &'static str,
+    pub calls: u64,
+    pub total: Duration,
+    pub children: Vec<SpanStats>,
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fn write_spans(
+            f: &mut Formatter<'_>,
+            spans: &[SpanStats],
+            depth: usize,
+        ) -> std::fmt::Result {
+            for span in spans {
+                writeln!(
+                    f,
+                    "{:indent$}{} {:.2?} ({} {})",
+                    "",
+                    span.name,
+                    span.total,
+                    span.calls,
+                    if span.calls == 1 { "call" } else { "calls" },
+                    indent = 2 * (depth + 1)
+                )?;
+                write_spans(f, &span.children, depth + 1)?;
+            }
+
+            Ok(())
+        }
+
+        write_spans(f, &self.spans, 0)
+    }
+}
+
+#[must_use = "a span ends when it is dropped"]
+pub struct Span {
+    #[cfg(feature = "profile")]
+    start: std::time::Instant,
+}
+
+/// Times the rest of the enclosing scope as `name`, nested under any span that is still open.
+/// Without the `profile` feature this does nothing.
+#[inline(always)]
+pub fn span(name: &'static str) -> Span {
+    #[cfg(feature = "profile")]
+    {
+        recording::enter(name);
+        Span {
+            start: std::time::Instant::now(),
+        }
+    }
+    #[cfg(not(feature = "profile"))]
+    {
+        let _ = name;
+        Span {}
+    }
+}
+
+#[cfg(feature = "profile")]
+impl Drop for Span {
+    fn drop(&mut self) {
+        recording::exit(self.start.elapsed());
+    }
+}
+
+/// Nests the spans recorded while parsing under a `parse` span, ahead of the part's own spans.
+pub(crate) fn with_parse(
+    parse: Option<&Profile>,
+    parse_time: Duration,
+    part: Option<Profile>,
+) -> Option<Profile> {
+    let Some(parse) = parse else {
+        return part;
+    };
+
+    let mut spans = vec![SpanStats {
+        name: "parse",
+        calls: 1,
+        total: parse_time,
+        children: parse.spans.clone(),
+    }];
+    spans.extend(part.into_iter().flat_map(|profile| profile.spans));
+
+    Some(Profile { spans })
+}
+
+/// Runs `f` and collects the spans it opened on this thread, if any.
+#[cfg(feature = "profile")]
+pub(crate) fn profile<F, R>(f: F) -> (R, Option<Profile>)
+where
+    F: FnOnce() -> R,
+{
+    recording::reset();
+    let result = f();
+    let profile = recording::take();
+
+    (
+        result,
+        Some(profile).filter(|profile| !profile.spans.is_empty()),
+    )
+}
+
+#[cfg(not(feature = "profile"))]
+pub(crate) fn profile<F, R>(f: F) -> (R, Option<Profile>)
+where
+    F: FnOnce() -> R,
+{
+    (f(), None)
+}
+
+// Spans are recorded per thread so parts solved concurrently get separate trees.
+#[cfg(feature = "profile")]
+mod recording {
+    use std::cell::RefCell;
+    use std::time::Duration;
+
+    use super::{Profile, SpanStats};
+
+    #[derive(Default)]
+    struct Recorder {
+        roots: Vec<SpanStats>,
+        // Child indices from the roots down to the innermost open span.
+        open: Vec<usize>,
+    }
+
+    impl Recorder {
+        fn siblings(&mut self) -> &mut Vec<SpanStats> {
+            let mut spans = &mut self.roots;
+            for &index in &self.open {
+                spans = &mut spans[index].children;
+            }
+            spans
+        }
+
+        fn current(&mut self) -> Option<&mut SpanStats> {
+            let (&last, path) = self.open.split_last()?;
+            let mut spans = &mut self.roots;
+            for &index in path {
+                spans = &mut spans[index].children;
+            }
+            spans.get_mut(last)
+        }
+    }
+
+    thread_local! {
+        static RECORDER: RefCell<Recorder> = RefCell::new(Recorder::default());
+    }
+
+    pub(super) fn enter(name: &'static str) {
+        RECORDER.with(|recorder| {
+            let mut recorder = recorder.borrow_mut();
+            let siblings = recorder.siblings();
+            let index = match siblings.iter().position(|span| span.name == name) {
+                Some(index) => index,
+                None => {
+                    siblings.push(SpanStats {
+                        name,
+                        calls: 0,
+                        total: Duration::ZERO,
+                        children: vec![],
+                    });
+                    siblings.len() - 1
+                }
+            };
+            recorder.open.push(index);
+        });
+    }
+
+    pub(super) fn exit(elapsed: Duration) {
+        RECORDER.with(|recorder| {
+            let mut recorder = recorder.borrow_mut();
+            if let Some(span) = recorder.current() {
+                span.calls += 1;
+                span.total += elapsed;
+            }
+            recorder.open.pop();
+        });
+    }
+
+    pub(super) fn reset() {
+        RECORDER.with(|recorder| *recorder.borrow_mut() = Recorder::default());
+    }
+
+    pub(super) fn take() -> Profile {
+        let recorder = RECORDER.with(|recorder| recorder.take());
+        Profile {
+            spans: recorder.roots,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let profile = Profile {
+            spans: vec![SpanStats {
+                name: "hands",
+                calls: 1,
+                total: Duration::from_millis(3),
+                children: vec![SpanStats {
+                    name: "rank",
+                    calls: 1000,
+                    total: Duration::from_micros(1500),
+                    children: vec![],
+                }],
+            }],
+        };
+
+        assert_eq!(
+            profile.to_string(),
+            "  hands 3.00ms (1 call)\n    rank 1.50ms (1000 calls)\n"
+        );
+    }
+
+    #[cfg(feature = "profile")]
+    #[test]
+    fn test_profile() {
+        let (answer, profile) = profile(|| {
+            let _outer = span("outer");
+            for _ in 0..3 {
+                let _inner = span("inner");
+            }
+            {
+                let _other = span("other");
+            }
+            42
+        });
+
+        let profile = profile.unwrap();
+        assert_eq!(answer, 42);
+        assert_eq!(profile.spans.len(), 1);
+        let outer = &profile.spans[0];
+        assert_eq!((outer.name, outer.calls), ("outer", 1));
+        assert_eq!(
+            outer
+                .children
+                .iter()
+                .map(|span| (span.name, span.calls))
+                .collect::<Vec<_>>(),
+            [("inner", 3), ("other", 1)]
+        );
+        assert!(outer.total >= outer.children.iter().map(|span| span.total).sum());
+    }
+
+    #[cfg(not(feature = "profile"))]
+    #[test]
+    fn test_profile_disabled() {
+        let (answer, profile) = profile(|| {
+            let _span = span("ignored");
+            42
+        });
+
+        assert_eq!(answer, 42);
+        assert_eq!(profile, None);
+        assert_eq!(std::mem::size_of::<Span>(), 0);
+    }
+}
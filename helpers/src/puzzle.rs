@@ -0,0 +1,225 @@
+use std::any::type_name;
+
+use crate::input::input_lines;
+use crate::panic::raise;
+use crate::InputError;
+
+/// Parses a record from `text`, a slice of `input` used to report positions in errors.
+///
+/// Implemented for the primitive types, `String` and `&str`, and derived with
+/// `#[derive(PuzzleParse)]` (feature `derive`) for structs laid out on a line and for enums of
+/// unit variants.
+pub trait PuzzleParse<'a>: Sized {
+    fn parse_in(input: &'a str, text: &'a str) -> Result<Self, InputError>;
+}
+
+macro_rules! from_str_impls {
+    ($($type:ty),+) => {
+        $(
+            impl<'a> PuzzleParse<'a> for $type {
+                fn parse_in(input: &'a str, text: &'a str) -> Result<Self, InputError> {
+                    text.trim()
+                        .parse()
+                        .map_err(|error: <$type as std::str::FromStr>::Err| {
+                            InputError::at::<$type>(input, text.trim(), error.to_string())
+                        })
+                }
+            }
+        )+
+    };
+}
+
+from_str_impls!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, char, bool, String
+);
+
+impl<'a> PuzzleParse<'a> for &'a str {
+    fn parse_in(_input: &'a str, text: &'a str) -> Result<Self, InputError> {
+        Ok(text.trim())
+    }
+}
+
+/// Parses every non-empty line of `input` as a `T`, panicking with a diagnostic on failure.
+#[track_caller]
+pub fn parse_records<'a, T: PuzzleParse<'a>>(input: &'a str) -> Vec<T> {
+    match try_parse_records(input) {
+        Ok(records) => records,
+        Err(error) => raise(error),
+    }
+}
+
+pub fn try_parse_records<'a, T: PuzzleParse<'a>>(input: &'a str) -> Result<Vec<T>, InputError> {
+    input_lines::<Vec<_>>(input)
+        .into_iter()
+        .map(|line| T::parse_in(input, line))
+        .collect()
+}
+
+// Used by the derived implementations.
+
+#[doc(hidden)]
+pub fn split_list<'a>(text: &'a str, separator: Option<&str>) -> Vec<&'a str> {
+    match separator {
+        Some(separator) => text
+            .split(separator)
+            .map(|item| item.trim())
+            .filter(|item| !item.is_empty())
+            .collect(),
+        None => text.split_whitespace().collect(),
+    }
+}
+
+#[doc(hidden)]
+pub fn split_tuple<'a, T>(
+    input: &'a str,
+    text: &'a str,
+    separator: Option<&str>,
+    len: usize,
+) -> Result<Vec<&'a str>, InputError> {
+    let text = text.trim();
+    let parts: Vec<&str> = match separator {
+        Some(separator) => text
+            .splitn(len, separator)
+            .map(|part| part.trim())
+            .collect(),
+        None => text
+            .splitn(len, char::is_whitespace)
+            .map(|part| part.trim())
+            .collect(),
+    };
+
+    if parts.len() == len {
+        Ok(parts)
+    } else {
+        Err(InputError::new(
+            input,
+            text,
+            type_name::<T>().to_string(),
+            format!(
+                "expected {} parts separated by `{}`",
+                len,
+                separator.unwrap_or(" ")
+            ),
+        ))
+    }
+}
+
+#[doc(hidden)]
+pub fn unknown_variant<T>(input: &str, text: &str, names: &[&str]) -> InputError {
+    let names: Vec<String> = names.iter().map(|name| format!("`{}`", name)).collect();
+
+    InputError::at::<T>(input, text, format!("expected one of {}", names.join(", ")))
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use crate::PuzzleParse;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, PuzzleParse)]
+    enum Color {
+        Red,
+        Green,
+        Blue,
+    }
+
+    #[derive(Debug, PartialEq, PuzzleParse)]
+    struct Game {
+        #[puzzle(prefix = "Game")]
+        id: u32,
+        #[puzzle(prefix = ":", sep = ["; ", ", ", " "])]
+        rounds: Vec<Vec<(u32, Color)>>,
+    }
+
+    #[derive(Debug, PartialEq, PuzzleParse)]
+    struct Card {
+        #[puzzle(prefix = "Card")]
+        id: u32,
+        #[puzzle(prefix = ":")]
+        winning: Vec<u32>,
+        #[puzzle(prefix = "|")]
+        numbers: Vec<u32>,
+    }
+
+    #[derive(Debug, PartialEq, PuzzleParse)]
+    struct Node<'a> {
+        name: &'a str,
+        #[puzzle(prefix = " = (")]
+        left: &'a str,
+        #[puzzle(prefix = ", ", suffix = ")")]
+        right: &'a str,
+    }
+
+    #[derive(Debug, PartialEq, PuzzleParse)]
+    struct Hand {
+        cards: String,
+        bid: usize,
+    }
+
+    #[test]
+    fn test_derive_struct() {
+        let games: Vec<Game> = parse_records(
+            "
+Game 1: 3 blue, 4 red; 1 red, 2 green
+Game 2: 1 blue
+",
+        );
+
+        assert_eq!(
+            games,
+            [
+                Game {
+                    id: 1,
+                    rounds: vec![
+                        vec![(3, Color::Blue), (4, Color::Red)],
+                        vec![(1, Color::Red), (2, Color::Green)]
+                    ]
+                },
+                Game {
+                    id: 2,
+                    rounds: vec![vec![(1, Color::Blue)]]
+                }
+            ]
+        );
+
+        let cards: Vec<Card> = parse_records("Card   1: 41 48 83 | 83 86  6 31");
+        assert_eq!(
+            cards,
+            [Card {
+                id: 1,
+                winning: vec![41, 48, 83],
+                numbers: vec![83, 86, 6, 31]
+            }]
+        );
+
+        let nodes: Vec<Node> = parse_records("AAA = (BBB, CCC)");
+        assert_eq!(
+            nodes,
+            [Node {
+                name: "AAA",
+                left: "BBB",
+                right: "CCC"
+            }]
+        );
+
+        let hands: Vec<Hand> = parse_records("32T3K 765\nT55J5 684");
+        assert_eq!(hands[1].cards, "T55J5");
+        assert_eq!(hands[1].bid, 684);
+    }
+
+    #[test]
+    fn test_derive_errors() {
+        let error = try_parse_records::<Game>("Game 1: 3 blue\nGame 2: 4 purple").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 11));
+        assert_eq!(error.text, "purple");
+        assert_eq!(error.reason, "expected one of `red`, `green`, `blue`");
+
+        let error = try_parse_records::<Card>("Card 1: 41 x8 | 83").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 12));
+        assert_eq!(error.target, "u32");
+
+        let error = try_parse_records::<Node>("AAA = BBB, CCC").unwrap_err();
+        assert_eq!(error.reason, "expected ` = (`");
+    }
+}
@@ -0,0 +1,426 @@
+use std::fmt::{Display, Formatter, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::{AllocStats, InputError, Profile, Stats};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunReport {
+    pub day: Option<u8>,
+    pub part: String,
+    pub answer: String,
+    pub parse_time: Option<Duration>,
+    pub solve_time: Duration,
+    pub status: Status,
+    pub bench: Option<Stats>,
+    pub alloc: Option<AllocStats>,
+    pub profile: Option<Profile>,
+    pub input_error: Option<InputError>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Unknown,
+    Correct,
+    Wrong {
+        expected: String,
+    },
+    Panicked {
+        message: String,
+        location: Option<String>,
+    },
+    TimedOut {
+        limit: Duration,
+    },
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl RunReport {
+    pub fn new(day: Option<u8>, part: &str, answer: String, solve_time: Duration) -> Self {
+        RunReport {
+            day,
+            part: part.to_string(),
+            answer,
+            parse_time: None,
+            solve_time,
+            status: Status::Unknown,
+            bench: None,
+            alloc: None,
+            profile: None,
+            input_error: None,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self.day {
+            Some(day) => format!("day{} {}", day, self.part),
+            None => self.part.clone(),
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        self.status.is_failure()
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.parse_time.unwrap_or_default() + self.solve_time
+    }
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Status::Wrong { .. } | Status::Panicked { .. } | Status::TimedOut { .. }
+        )
+    }
+}
+
+impl Display for RunReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let answer = match &self.status {
+            Status::Panicked { message, location } => match location {
+                Some(location) => format!("panicked at {}: {}", location, message),
+                None => format!("panicked: {}", message),
+            },
+            Status::TimedOut { .. } => "TIMEOUT".to_string(),
+            _ => self.answer.clone(),
+        };
+
+        let parse = self
+            .parse_time
+            .map_or(String::new(), |time| format!("parse {:.2?}, ", time));
+        let timing = match &self.bench {
+            Some(stats) => format!(
+                "{}median {:.2?}, min {:.2?}, mean {:.2?}, p95 {:.2?}, σ {:.2?}, {} runs",
+                parse, stats.median, stats.min, stats.mean, stats.p95, stats.stddev, stats.samples
+            ),
+            None if self.parse_time.is_some() => format!("{}solve {:.2?}", parse, self.solve_time),
+            None => format!("{:.2?}", self.solve_time),
+        };
+        let alloc = self
+            .alloc
+            .map_or(String::new(), |alloc| format!(", {}", alloc));
+
+        write!(f, "{}: {} ({}{})", self.name(), answer, timing, alloc)?;
+
+        match &self.status {
+            Status::Unknown => Ok(()),
+            Status::Correct => write!(f, " ✓"),
+            Status::Wrong { expected } => write!(f, " ✗ expected {}", expected),
+            Status::Panicked { .. } | Status::TimedOut { .. } => write!(f, " ✗"),
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Unknown => write!(f, "unknown"),
+            Status::Correct => write!(f, "correct"),
+            Status::Wrong { .. } => write!(f, "wrong"),
+            Status::Panicked { .. } => write!(f, "panicked"),
+            Status::TimedOut { .. } => write!(f, "timeout"),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" | "txt" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "markdown" | "md" => Ok(Format::Markdown),
+            _ => Err(format!("Unknown report format `{}`", s)),
+        }
+    }
+}
+
+impl Format {
+    pub fn render(&self, reports: &[RunReport]) -> String {
+        match self {
+            Format::Text => render_text(reports),
+            Format::Json => render_json(reports),
+            Format::Csv => render_csv(reports),
+            Format::Markdown => render_markdown(reports),
+        }
+    }
+}
+
+fn render_text(reports: &[RunReport]) -> String {
+    reports
+        .iter()
+        .map(|report| format!("{}\n\n", report))
+        .collect()
+}
+
+fn render_json(reports: &[RunReport]) -> String {
+    let mut output = String::from("[");
+
+    for (index, report) in reports.iter().enumerate() {
+        if index > 0 {
+            output.push(',');
+        }
+
+        write!(
+            output,
+            "\n  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"parse_ns\": {}, \"solve_ns\": {}, \"status\": {}, \"bench\": {}, \"alloc\": {}}}",
+            report.day.map_or("null".to_string(), |day| day.to_string()),
+            json_string(&report.part),
+            json_string(&report.answer),
+            report
+                .parse_time
+                .map_or("null".to_string(), |time| time.as_nanos().to_string()),
+            report.solve_time.as_nanos(),
+            json_string(&report.status.to_string()),
+            report.bench.as_ref().map_or("null".to_string(), json_stats),
+            report.alloc.as_ref().map_or("null".to_string(), json_alloc),
+        )
+        .unwrap();
+    }
+
+    if !reports.is_empty() {
+        output.push('\n');
+    }
+    output.push_str("]\n");
+
+    output
+}
+
+fn render_csv(reports: &[RunReport]) -> String {
+    let mut output = String::from(
+        "day,part,answer,parse_ns,solve_ns,status,samples,min_ns,median_ns,mean_ns,p95_ns,stddev_ns,allocations,alloc_bytes,peak_bytes\n",
+    );
+
+    for report in reports {
+        writeln!(
+            output,
+            "{},{},{},{},{},{},{},{}",
+            report.day.map_or(String::new(), |day| day.to_string()),
+            csv_field(&report.part),
+            csv_field(&report.answer),
+            report
+                .parse_time
+                .map_or(String::new(), |time| time.as_nanos().to_string()),
+            report.solve_time.as_nanos(),
+            csv_field(&report.status.to_string()),
+            report.bench.as_ref().map_or(",,,,,".to_string(), csv_stats),
+            report.alloc.as_ref().map_or(",,".to_string(), csv_alloc),
+        )
+        .unwrap();
+    }
+
+    output
+}
+
+fn render_markdown(reports: &[RunReport]) -> String {
+    let mut output = String::from(
+        "| Day | Part | Answer | Parse | Solve | Status |\n|---|---|---|---|---|---|\n",
+    );
+
+    for report in reports {
+        writeln!(
+            output,
+            "| {} | {} | {} | {} | {:.2?} | {} |",
+            report.day.map_or("-".to_string(), |day| day.to_string()),
+            markdown_cell(&report.part),
+            markdown_cell(&report.answer),
+            report
+                .parse_time
+                .map_or("-".to_string(), |time| format!("{:.2?}", time)),
+            report.solve_time,
+            report.status,
+        )
+        .unwrap();
+    }
+
+    output
+}
+
+fn json_stats(stats: &Stats) -> String {
+    format!(
+        "{{\"samples\": {}, \"min_ns\": {}, \"median_ns\": {}, \"mean_ns\": {}, \"p95_ns\": {}, \"stddev_ns\": {}}}",
+        stats.samples,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.mean.as_nanos(),
+        stats.p95.as_nanos(),
+        stats.stddev.as_nanos()
+    )
+}
+
+fn json_alloc(alloc: &AllocStats) -> String {
+    format!(
+        "{{\"allocations\": {}, \"bytes\": {}, \"peak_bytes\": {}}}",
+        alloc.allocations, alloc.bytes, alloc.peak
+    )
+}
+
+fn csv_alloc(alloc: &AllocStats) -> String {
+    format!("{},{},{}", alloc.allocations, alloc.bytes, alloc.peak)
+}
+
+fn csv_stats(stats: &Stats) -> String {
+    format!(
+        "{},{},{},{},{},{}",
+        stats.samples,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.mean.as_nanos(),
+        stats.p95.as_nanos(),
+        stats.stddev.as_nanos()
+    )
+}
+
+fn json_string(value: &str) -> String {
+    let mut output = String::with_capacity(value.len() + 2);
+    output.push('"');
+
+    for char in value.chars() {
+        match char {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            char if (char as u32) < 0x20 => write!(output, "\\u{:04x}", char as u32).unwrap(),
+            char => output.push(char),
+        }
+    }
+
+    output.push('"');
+    output
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reports() -> Vec<RunReport> {
+        let mut first = RunReport::new(
+            Some(1),
+            "part1",
+            "3066".to_string(),
+            Duration::from_millis(10),
+        );
+        first.parse_time = Some(Duration::from_micros(5));
+        first.status = Status::Correct;
+        first.bench = Stats::from_samples(&[Duration::from_millis(10), Duration::from_millis(10)]);
+        first.alloc = Some(AllocStats {
+            allocations: 3,
+            bytes: 2048,
+            peak: 1024,
+        });
+
+        let second = RunReport::new(
+            None,
+            "part2",
+            "a,\"b\"".to_string(),
+            Duration::from_nanos(1500),
+        );
+
+        vec![first, second]
+    }
+
+    #[test]
+    fn test_render_text() {
+        assert_eq!(
+            Format::Text.render(&reports()),
+            "day1 part1: 3066 (parse 5.00µs, median 10.00ms, min 10.00ms, mean 10.00ms, p95 10.00ms, σ 0.00ns, 2 runs, 3 allocs, 2.00 KiB, peak 1.00 KiB) ✓\n\npart2: a,\"b\" (1.50µs)\n\n"
+        );
+    }
+
+    #[test]
+    fn test_render_json() {
+        assert_eq!(
+            Format::Json.render(&reports()),
+            "[\n  {\"day\": 1, \"part\": \"part1\", \"answer\": \"3066\", \"parse_ns\": 5000, \"solve_ns\": 10000000, \"status\": \"correct\", \"bench\": {\"samples\": 2, \"min_ns\": 10000000, \"median_ns\": 10000000, \"mean_ns\": 10000000, \"p95_ns\": 10000000, \"stddev_ns\": 0}, \"alloc\": {\"allocations\": 3, \"bytes\": 2048, \"peak_bytes\": 1024}},\n  {\"day\": null, \"part\": \"part2\", \"answer\": \"a,\\\"b\\\"\", \"parse_ns\": null, \"solve_ns\": 1500, \"status\": \"unknown\", \"bench\": null, \"alloc\": null}\n]\n"
+        );
+        assert_eq!(Format::Json.render(&[]), "[]\n");
+    }
+
+    #[test]
+    fn test_render_csv() {
+        assert_eq!(
+            Format::Csv.render(&reports()),
+            "day,part,answer,parse_ns,solve_ns,status,samples,min_ns,median_ns,mean_ns,p95_ns,stddev_ns,allocations,alloc_bytes,peak_bytes\n1,part1,3066,5000,10000000,correct,2,10000000,10000000,10000000,10000000,0,3,2048,1024\n,part2,\"a,\"\"b\"\"\",,1500,unknown,,,,,,,,,\n"
+        );
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        assert_eq!(
+            Format::Markdown.render(&reports()),
+            "| Day | Part | Answer | Parse | Solve | Status |\n|---|---|---|---|---|---|\n| 1 | part1 | 3066 | 5.00µs | 10.00ms | correct |\n| - | part2 | a,\"b\" | - | 1.50µs | unknown |\n"
+        );
+    }
+
+    #[test]
+    fn test_render_wrong_status() {
+        let mut report =
+            RunReport::new(Some(2), "part1", "8".to_string(), Duration::from_millis(1));
+        report.status = Status::Wrong {
+            expected: "9".to_string(),
+        };
+
+        assert!(report.is_failure());
+        assert_eq!(report.to_string(), "day2 part1: 8 (1.00ms) ✗ expected 9");
+    }
+
+    #[test]
+    fn test_render_panicked_status() {
+        let mut report = RunReport::new(Some(5), "part2", String::new(), Duration::from_millis(1));
+        report.status = Status::Panicked {
+            message: "index out of bounds".to_string(),
+            location: Some("day5/src/lib.rs:12:9".to_string()),
+        };
+
+        assert!(report.is_failure());
+        assert_eq!(
+            report.to_string(),
+            "day5 part2: panicked at day5/src/lib.rs:12:9: index out of bounds (1.00ms) ✗"
+        );
+        assert_eq!(report.status.to_string(), "panicked");
+    }
+
+    #[test]
+    fn test_render_timed_out_status() {
+        let mut report = RunReport::new(Some(8), "part2", String::new(), Duration::from_secs(10));
+        report.status = Status::TimedOut {
+            limit: Duration::from_secs(10),
+        };
+
+        assert!(report.is_failure());
+        assert_eq!(report.to_string(), "day8 part2: TIMEOUT (10.00s) ✗");
+        assert_eq!(report.status.to_string(), "timeout");
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("JSON".parse::<Format>(), Ok(Format::Json));
+        assert_eq!("md".parse::<Format>(), Ok(Format::Markdown));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
@@ -1,42 +1,216 @@
+use std::env;
 use std::fmt::Display;
 use std::io::{stdout, Write};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
+use crate::alloc::track;
+use crate::bench::{measure, parse_duration};
+use crate::cancel::with_timeout;
 use crate::instant::BasicInstant;
+use crate::panic::{catch, Panic};
+use crate::profile::profile;
+use crate::{AllocStats, Bench, Profile, RunReport, Stats, Status};
 
-pub fn run<S, R>(name: &str, solver: S)
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RunOptions {
+    pub bench: Option<Bench>,
+    pub timeout: Option<Duration>,
+}
+
+impl RunOptions {
+    /// Reads `AOC_BENCH` and `AOC_TIMEOUT`, an empty value, `0`, `off` or `false` disables either.
+    pub fn from_env() -> Result<Self, String> {
+        Self::resolve(env::var("AOC_BENCH").ok(), env::var("AOC_TIMEOUT").ok())
+    }
+
+    fn resolve(bench: Option<String>, timeout: Option<String>) -> Result<Self, String> {
+        let enabled = |value: &String| {
+            !matches!(
+                value.trim().to_ascii_lowercase().as_str(),
+                "" | "0" | "off" | "false"
+            )
+        };
+
+        Ok(RunOptions {
+            bench: bench
+                .filter(enabled)
+                .map(|value| value.parse())
+                .transpose()
+                .map_err(|error| format!("AOC_BENCH: {}", error))?,
+            timeout: timeout
+                .filter(enabled)
+                .map(|value| parse_timeout(&value))
+                .transpose()
+                .map_err(|error| format!("AOC_TIMEOUT: {}", error))?,
+        })
+    }
+}
+
+/// Parses a time limit such as `30s` or `500ms`, plain numbers are seconds.
+pub fn parse_timeout(value: &str) -> Result<Duration, String> {
+    value
+        .trim()
+        .parse()
+        .map(Duration::from_secs)
+        .ok()
+        .or_else(|| parse_duration(value))
+        .ok_or_else(|| format!("Invalid time limit `{}`", value))
+}
+
+/// Like `run_with`, with the options read from the environment.
+pub fn run<S, R>(name: &str, solver: S) -> Result<RunReport, String>
 where
-    R: Display,
-    S: Fn() -> R,
+    R: Display + Send + 'static,
+    S: Fn() -> R + Send + Sync + 'static,
 {
-    raw_run(stdout(), Instant::now(), name, solver);
+    Ok(run_with(name, &RunOptions::from_env()?, solver))
 }
 
-fn raw_run<W, N, S, R>(mut writer: W, now: N, name: &str, solver: S)
+pub fn run_with<S, R>(name: &str, options: &RunOptions, solver: S) -> RunReport
+where
+    R: Display + Send + 'static,
+    S: Fn() -> R + Send + Sync + 'static,
+{
+    raw_run(stdout(), Instant::now(), name, options, solver)
+}
+
+fn raw_run<W, N, S, R>(
+    mut writer: W,
+    now: N,
+    name: &str,
+    options: &RunOptions,
+    solver: S,
+) -> RunReport
 where
     W: Write,
     N: BasicInstant,
-    S: Fn() -> R,
-    R: Display,
+    S: Fn() -> R + Send + Sync + 'static,
+    R: Display + Send + 'static,
+{
+    let report = solve(now, name, options, solver);
+    write_report(&mut writer, &report);
+
+    report
+}
+
+pub(crate) type Attempt = Result<
+    (
+        String,
+        Duration,
+        Option<Stats>,
+        Option<AllocStats>,
+        Option<Profile>,
+    ),
+    Panic,
+>;
+
+/// Solves a part with the time limit in `options`. A part that overruns it is left running on a
+/// detached thread, hence the `'static` bounds.
+pub(crate) fn solve<N, S, R>(now: N, name: &str, options: &RunOptions, solver: S) -> RunReport
+where
+    N: BasicInstant,
+    S: Fn() -> R + Send + Sync + 'static,
+    R: Display + Send + 'static,
+{
+    match options.timeout {
+        Some(limit) => {
+            let bench = options.bench;
+            match with_timeout(limit, move || attempt(&now, bench.as_ref(), &solver)) {
+                Some(attempt) => into_report(name, &N::now(), attempt),
+                None => timed_out(name, limit),
+            }
+        }
+        None => into_report(name, &now, attempt(&now, options.bench.as_ref(), &solver)),
+    }
+}
+
+/// Like `solve`, ignoring the time limit so `solver` can borrow.
+pub(crate) fn solve_untimed<N, S, R>(
+    now: N,
+    name: &str,
+    options: &RunOptions,
+    solver: S,
+) -> RunReport
+where
+    N: BasicInstant,
+    S: Fn() -> R + Sync,
+    R: Display + Send,
 {
-    let solution = solver();
+    into_report(name, &now, attempt(&now, options.bench.as_ref(), &solver))
+}
 
-    write!(
-        &mut writer,
-        "{}: {} ({:.2?})\n\n",
-        name,
-        solution,
-        now.elapsed()
-    )
-    .expect("Something went wrong writing the solution!")
+pub(crate) fn timed_out(name: &str, limit: Duration) -> RunReport {
+    let mut report = RunReport::new(None, name, String::new(), limit);
+    report.status = Status::TimedOut { limit };
+    report
+}
+
+pub(crate) fn attempt<N, S, R>(now: &N, bench: Option<&Bench>, solver: &S) -> Attempt
+where
+    N: BasicInstant,
+    S: Fn() -> R + Sync,
+    R: Display + Send,
+{
+    catch(|| match bench {
+        Some(bench) => {
+            let ((_, alloc), profile) = profile(|| track(solver));
+            let (solution, stats) = measure::<N, _, _>(solver, bench);
+            (
+                solution.to_string(),
+                stats.median,
+                Some(stats),
+                alloc,
+                profile,
+            )
+        }
+        None => {
+            let ((solution, alloc), profile) = profile(|| track(solver));
+            (solution.to_string(), now.elapsed(), None, alloc, profile)
+        }
+    })
+}
+
+pub(crate) fn into_report<N: BasicInstant>(name: &str, now: &N, attempt: Attempt) -> RunReport {
+    match attempt {
+        Ok((answer, solve_time, bench, alloc, profile)) => {
+            let mut report = RunReport::new(None, name, answer, solve_time);
+            report.bench = bench;
+            report.alloc = alloc;
+            report.profile = profile;
+            report
+        }
+        Err(Panic {
+            message,
+            location,
+            input_error,
+        }) => {
+            let mut report = RunReport::new(None, name, String::new(), now.elapsed());
+            report.status = Status::Panicked { message, location };
+            report.input_error = input_error.map(|error| *error);
+            report
+        }
+    }
+}
+
+pub(crate) fn write_report<W: Write>(mut writer: W, report: &RunReport) {
+    let profile = report
+        .profile
+        .as_ref()
+        .map_or(String::new(), |profile| profile.to_string());
+    let input_error = report
+        .input_error
+        .as_ref()
+        .map_or(String::new(), |error| error.render());
+
+    write!(&mut writer, "{}\n{}{}\n", report, profile, input_error)
+        .expect("Something went wrong writing the solution!");
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::instant;
+    use crate::{instant, CancelToken, Profile, SpanStats};
 
     use super::*;
-    use std::time::Duration;
 
     #[test]
     fn test_run() {
@@ -49,11 +223,183 @@ mod tests {
                 .fold(0, |accumulator, entry| accumulator + entry)
         }
 
-        raw_run(&mut output, instant::fake::Instant::now(), "test", || {
-            solver(&input)
-        });
+        let report = raw_run(
+            &mut output,
+            instant::fake::Instant::now(),
+            "test",
+            &RunOptions::default(),
+            move || solver(&input),
+        );
         let output = String::from_utf8(output).expect("Not UTF-8");
 
         assert_eq!(output, "test: 3066 (10.00ms)\n\n");
+        assert_eq!(report.part, "test");
+        assert_eq!(report.answer, "3066");
+        assert_eq!(report.solve_time, Duration::from_millis(10));
+        assert_eq!(report.status, Status::Unknown);
+    }
+
+    #[test]
+    fn test_run_bench() {
+        instant::fake::with_fake_samples(
+            &[4, 2, 3, 1, 5]
+                .iter()
+                .map(|&millis| Duration::from_millis(millis))
+                .collect::<Vec<_>>(),
+        );
+        let mut output = Vec::new();
+        let options = RunOptions {
+            bench: Some(Bench::iterations(5)),
+            ..RunOptions::default()
+        };
+
+        let report = raw_run(
+            &mut output,
+            instant::fake::Instant::now(),
+            "test",
+            &options,
+            || 42,
+        );
+        let output = String::from_utf8(output).expect("Not UTF-8");
+
+        assert_eq!(
+            output,
+            "test: 42 (median 3.00ms, min 1.00ms, mean 3.00ms, p95 5.00ms, σ 1.41ms, 5 runs)\n\n"
+        );
+        assert_eq!(report.solve_time, Duration::from_millis(3));
+        assert_eq!(report.bench.unwrap().samples, 5);
+    }
+
+    #[test]
+    fn test_run_panic() {
+        instant::fake::with_fake_elapsed(Duration::from_millis(10));
+        let mut output = Vec::new();
+        let input = ["Game 1", "Game"];
+
+        let report = raw_run(
+            &mut output,
+            instant::fake::Instant::now(),
+            "test",
+            &RunOptions::default(),
+            move || input[1].split_once(' ').unwrap().1.len(),
+        );
+        let output = String::from_utf8(output).expect("Not UTF-8");
+
+        assert!(report.is_failure());
+        assert_eq!(report.answer, "");
+        let Status::Panicked { message, location } = &report.status else {
+            panic!("Expected a panic, got {:?}", report.status);
+        };
+        assert_eq!(message, "called `Option::unwrap()` on a `None` value");
+        assert!(location.as_ref().unwrap().starts_with(file!()));
+        assert_eq!(
+            output,
+            format!(
+                "test: panicked at {}: {} (10.00ms) ✗\n\n",
+                location.as_ref().unwrap(),
+                message
+            )
+        );
+    }
+
+    #[test]
+    fn test_run_input_error() {
+        instant::fake::with_fake_elapsed(Duration::from_millis(10));
+        let mut output = Vec::new();
+
+        let report = raw_run(
+            &mut output,
+            instant::fake::Instant::now(),
+            "test",
+            &RunOptions::default(),
+            || crate::parse_input::<u32, Vec<u32>>("1\nx").len(),
+        );
+        let output = String::from_utf8(output).expect("Not UTF-8");
+
+        let error = report.input_error.as_ref().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert!(output.ends_with(&format!(") ✗\n{}\n", error.render())));
+        assert!(output.starts_with("test: panicked at src/run.rs:"));
+        assert!(output.contains(": line 2, column 1: cannot parse `x` as u32"));
+    }
+
+    #[test]
+    fn test_run_timeout() {
+        let mut output = Vec::new();
+        let options = RunOptions {
+            timeout: Some(Duration::from_millis(10)),
+            ..RunOptions::default()
+        };
+
+        let report = raw_run(&mut output, Instant::now(), "test", &options, || {
+            let token = CancelToken::current();
+            while !token.is_cancelled() {}
+            42
+        });
+        let output = String::from_utf8(output).expect("Not UTF-8");
+
+        assert_eq!(
+            report.status,
+            Status::TimedOut {
+                limit: Duration::from_millis(10)
+            }
+        );
+        assert_eq!(output, "test: TIMEOUT (10.00ms) ✗\n\n");
+    }
+
+    #[test]
+    fn test_write_report_profile() {
+        let mut output = Vec::new();
+        let mut report = RunReport::new(Some(7), "part1", "6440".to_string(), Duration::ZERO);
+        report.profile = Some(Profile {
+            spans: vec![SpanStats {
+                name: "sort",
+                calls: 1,
+                total: Duration::ZERO,
+                children: vec![],
+            }],
+        });
+
+        write_report(&mut output, &report);
+        let output = String::from_utf8(output).expect("Not UTF-8");
+
+        assert_eq!(
+            output,
+            "day7 part1: 6440 (0.00ns)\n  sort 0.00ns (1 call)\n\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(parse_timeout("30"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_timeout("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_timeout("250ms"), Ok(Duration::from_millis(250)));
+        assert!(parse_timeout("soon").is_err());
+    }
+
+    #[test]
+    fn test_resolve_options() {
+        let resolve = |bench: &str, timeout: &str| {
+            RunOptions::resolve(Some(bench.into()), Some(timeout.into()))
+        };
+
+        assert_eq!(RunOptions::resolve(None, None), Ok(RunOptions::default()));
+        assert_eq!(resolve("", "0"), Ok(RunOptions::default()));
+        assert_eq!(resolve("off", "false"), Ok(RunOptions::default()));
+        assert_eq!(
+            resolve("on", "10s"),
+            Ok(RunOptions {
+                bench: Some(Bench::default()),
+                timeout: Some(Duration::from_secs(10)),
+            })
+        );
+        assert_eq!(
+            resolve("fast", ""),
+            Err("AOC_BENCH: Invalid benchmark setting `fast`".to_string())
+        );
+        assert_eq!(
+            resolve("", "soon"),
+            Err("AOC_TIMEOUT: Invalid time limit `soon`".to_string())
+        );
     }
 }
@@ -0,0 +1,367 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::{split_list, InputError};
+
+/// Extracts the `{}` fields of `pattern` from a line and parses them as the given types, returning
+/// a `Result` with a single value or a tuple.
+///
+/// `&str` fields are borrowed from the line, `{:SEP}` fields are lists split on `SEP` and parsed
+/// into a `Vec`, `{{` and `}}` match literal braces.
+///
+/// ```
+/// use helpers::scan;
+///
+/// let (id, rounds) = scan!("Game 12: 3 blue, 4 red", "Game {}: {:, }", u32, Vec<&str>).unwrap();
+/// assert_eq!((id, rounds), (12, vec!["3 blue", "4 red"]));
+///
+/// let (node, left, right) = scan!("AAA = (BBB, CCC)", "{} = ({}, {})", &str, &str, &str).unwrap();
+/// assert_eq!((node, left, right), ("AAA", "BBB", "CCC"));
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $pattern:expr, $($types:tt)+) => {{
+        let line: &str = $line;
+        $crate::scan_in!(line, line, $pattern, $($types)+)
+    }};
+}
+
+/// Like `scan!`, for a `line` of `input`, so errors point at the line and column in the whole input.
+///
+/// ```
+/// use helpers::scan_in;
+///
+/// let input = "AAA = (BBB, CCC)\nBBB = DDD";
+/// let line = input.lines().nth(1).unwrap();
+/// let error = scan_in!(input, line, "{} = ({}, {})", &str, &str, &str).unwrap_err();
+/// assert_eq!((error.line, error.column), (2, 1));
+/// ```
+#[macro_export]
+macro_rules! scan_in {
+    ($input:expr, $line:expr, $pattern:expr, $($types:tt)+) => {
+        $crate::__scan!(@munch [$input, $line, $pattern] [] [] $($types)+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __scan {
+    // Splits the types on commas, keeping each one as raw tokens so `&str` and `Vec<..>` can be
+    // recognised below.
+    (@munch $args:tt [$($done:tt)*] [$($current:tt)+] , $($rest:tt)*) => {
+        $crate::__scan!(@munch $args [$($done)* [$($current)+]] [] $($rest)*)
+    };
+    (@munch $args:tt [$($done:tt)*] [$($current:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__scan!(@munch $args [$($done)*] [$($current)* $next] $($rest)*)
+    };
+    (@munch $args:tt [$($done:tt)*] [$($current:tt)+]) => {
+        $crate::__scan!(@build $args [$($done)* [$($current)+]])
+    };
+    (@munch $args:tt [$($done:tt)*] []) => {
+        $crate::__scan!(@build $args [$($done)*])
+    };
+
+    (@build [$input:expr, $line:expr, $pattern:expr] [[$($type:tt)+]]) => {{
+        $crate::scan_line($input, $line, $pattern).and_then(|fields| {
+            let mut fields = $crate::Fields::new($pattern, fields);
+            let value = $crate::__scan!(@field fields.next_field(), $($type)+)?;
+            fields.finish();
+            Ok::<_, $crate::InputError>(value)
+        })
+    }};
+    (@build [$input:expr, $line:expr, $pattern:expr] [$([$($type:tt)+])+]) => {{
+        $crate::scan_line($input, $line, $pattern).and_then(|fields| {
+            let mut fields = $crate::Fields::new($pattern, fields);
+            let values = ($($crate::__scan!(@field fields.next_field(), $($type)+)?,)+);
+            fields.finish();
+            Ok::<_, $crate::InputError>(values)
+        })
+    }};
+
+    (@field $field:expr, &str) => {
+        Ok::<_, $crate::InputError>($field.text())
+    };
+    (@field $field:expr, Vec<&str>) => {
+        Ok::<_, $crate::InputError>($field.items())
+    };
+    (@field $field:expr, Vec<$type:ty>) => {
+        $field.list::<$type>()
+    };
+    (@field $field:expr, $type:ty) => {
+        $field.parse::<$type>()
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Field<'a> {
+    input: &'a str,
+    text: &'a str,
+    separator: Option<&'a str>,
+}
+
+impl<'a> Field<'a> {
+    pub fn text(&self) -> &'a str {
+        self.text.trim()
+    }
+
+    pub fn parse<T>(&self) -> Result<T, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        parse_item(self.input, self.text())
+    }
+
+    /// The list items, split on the field's separator or on whitespace for a plain `{}`.
+    pub fn items(&self) -> Vec<&'a str> {
+        split_list(self.text, self.separator)
+    }
+
+    pub fn list<T>(&self) -> Result<Vec<T>, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.items()
+            .into_iter()
+            .map(|item| parse_item(self.input, item))
+            .collect()
+    }
+}
+
+fn parse_item<T>(input: &str, text: &str) -> Result<T, InputError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.parse()
+        .map_err(|error: T::Err| InputError::at::<T>(input, text, error.to_string()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment<'p> {
+    Literal(Cow<'p, str>),
+    Field(Option<&'p str>),
+}
+
+// Literals borrow from the pattern unless they contain escaped braces.
+fn append<'p>(literal: &mut Option<Cow<'p, str>>, text: &'p str) {
+    match literal {
+        Some(literal) => literal.to_mut().push_str(text),
+        None => *literal = Some(Cow::Borrowed(text)),
+    }
+}
+
+fn segments(pattern: &str) -> Vec<Segment<'_>> {
+    let mut segments = vec![];
+    let mut literal: Option<Cow<str>> = None;
+    let mut rest = pattern;
+
+    while !rest.is_empty() {
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            append(&mut literal, &rest[..1]);
+            rest = &rest[2..];
+        } else if rest.starts_with('{') {
+            let end = rest
+                .find('}')
+                .unwrap_or_else(|| panic!("Unclosed `{{` in scan pattern `{}`", pattern));
+            let separator = match &rest[1..end] {
+                "" => None,
+                spec => Some(spec.strip_prefix(':').unwrap_or_else(|| {
+                    panic!("Invalid field `{{{}}}` in scan pattern `{}`", spec, pattern)
+                })),
+            };
+
+            match literal.take() {
+                Some(literal) => segments.push(Segment::Literal(literal)),
+                None if matches!(segments.last(), Some(Segment::Field(_))) => {
+                    panic!(
+                        "Adjacent fields in scan pattern `{}` are ambiguous",
+                        pattern
+                    )
+                }
+                None => {}
+            }
+            segments.push(Segment::Field(separator));
+            rest = &rest[end + 1..];
+        } else {
+            let end = rest[1..]
+                .find(['{', '}'])
+                .map_or(rest.len(), |index| index + 1);
+            append(&mut literal, &rest[..end]);
+            rest = &rest[end..];
+        }
+    }
+
+    if let Some(literal) = literal {
+        segments.push(Segment::Literal(literal));
+    }
+
+    segments
+}
+
+/// Matches `line`, a slice of `input`, against a `scan!` pattern and returns its fields.
+pub fn scan_line<'a>(
+    input: &'a str,
+    line: &'a str,
+    pattern: &'a str,
+) -> Result<Vec<Field<'a>>, InputError> {
+    let mismatch =
+        |text: &str, reason: String| InputError::new(input, text, format!("`{}`", pattern), reason);
+
+    let segments = segments(pattern);
+    let mut fields = vec![];
+    let mut position = 0;
+
+    for (index, segment) in segments.iter().enumerate() {
+        let rest = &line[position..];
+
+        match segment {
+            Segment::Literal(literal) => {
+                if !rest.starts_with(literal.as_ref()) {
+                    return Err(mismatch(rest, format!("expected `{}`", literal)));
+                }
+                position += literal.len();
+            }
+            Segment::Field(separator) => {
+                let end = match segments.get(index + 1) {
+                    Some(Segment::Literal(literal)) => rest
+                        .find(literal.as_ref())
+                        .ok_or_else(|| mismatch(rest, format!("expected `{}`", literal)))?,
+                    _ => rest.len(),
+                };
+
+                fields.push(Field {
+                    input,
+                    text: &rest[..end],
+                    separator: *separator,
+                });
+                position += end;
+            }
+        }
+    }
+
+    if position < line.len() {
+        return Err(mismatch(
+            &line[position..],
+            "unexpected text at the end of the line".to_string(),
+        ));
+    }
+
+    Ok(fields)
+}
+
+/// Hands out the fields of a `scan!` pattern, checking they match the number of types.
+#[doc(hidden)]
+pub struct Fields<'a> {
+    pattern: &'a str,
+    fields: std::vec::IntoIter<Field<'a>>,
+}
+
+impl<'a> Fields<'a> {
+    pub fn new(pattern: &'a str, fields: Vec<Field<'a>>) -> Self {
+        Fields {
+            pattern,
+            fields: fields.into_iter(),
+        }
+    }
+
+    pub fn next_field(&mut self) -> Field<'a> {
+        self.fields.next().unwrap_or_else(|| {
+            panic!(
+                "scan! pattern `{}` has fewer fields than types",
+                self.pattern
+            )
+        })
+    }
+
+    pub fn finish(self) {
+        assert!(
+            self.fields.len() == 0,
+            "scan! pattern `{}` has more fields than types",
+            self.pattern
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan() {
+        let (id, sets) = scan!(
+            "Game 1: 3 blue, 4 red; 1 red",
+            "Game {}: {:; }",
+            u32,
+            Vec<&str>
+        )
+        .unwrap();
+        assert_eq!(id, 1);
+        assert_eq!(sets, ["3 blue, 4 red", "1 red"]);
+
+        let (node, left, right) =
+            scan!("AAA = (BBB, CCC)", "{} = ({}, {})", &str, &str, &str).unwrap();
+        assert_eq!((node, left, right), ("AAA", "BBB", "CCC"));
+
+        let (card, winning, numbers) = scan!(
+            "Card   1: 41 48 83 | 83 86  6 31",
+            "Card {}: {: } | {}",
+            usize,
+            Vec<u32>,
+            Vec<u32>,
+        )
+        .unwrap();
+        assert_eq!(card, 1);
+        assert_eq!(winning, [41, 48, 83]);
+        assert_eq!(numbers, [83, 86, 6, 31]);
+
+        let value: i64 = scan!("{-12}", "{{{}}}", i64).unwrap();
+        assert_eq!(value, -12);
+    }
+
+    #[test]
+    fn test_scan_errors() {
+        let error = scan!("Gme 1: 3 blue", "Game {}: {}", u32, &str).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: cannot parse `Gme 1: 3 blue` as `Game {}: {}`: expected `Game `"
+        );
+
+        let error = scan!("Game 1 3 blue", "Game {}: {}", u32, &str).unwrap_err();
+        assert_eq!(error.column, 6);
+        assert_eq!(error.reason, "expected `: `");
+
+        let error = scan!("Game x: 3 blue", "Game {}: {}", u32, &str).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (6, "x"));
+        assert_eq!(error.target, "u32");
+
+        let error = scan!("1, 2, b", "{:, }", Vec<u8>).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (7, "b"));
+    }
+
+    #[test]
+    fn test_scan_line_in_input() {
+        let input = "AAA = (BBB, CCC)\nBBB = DDD\n";
+        let line = input.lines().nth(1).unwrap();
+
+        let error = scan_line(input, line, "{} = ({}, {})").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.reason, "expected ` = (`");
+
+        let input = "AAA = (BBB, CCC)\nBBB = (x, 12)\n";
+        let line = input.lines().nth(1).unwrap();
+
+        let error = scan_in!(input, line, "{} = ({}, {})", &str, u8, &str).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 8));
+        assert_eq!(error.line_text, "BBB = (x, 12)");
+    }
+
+    #[test]
+    #[should_panic(expected = "has more fields than types")]
+    fn test_scan_field_count() {
+        let _ = scan!("1 2", "{} {}", u32);
+    }
+}
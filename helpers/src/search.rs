@@ -0,0 +1,302 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use crate::{Coord, Grid, Point};
+
+/// A shortest path found on a grid, from the start to the goal inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    pub distance: u32,
+    pub coords: Vec<Coord>,
+}
+
+/// Finds the fewest steps from `start` to a cell matching `is_goal`, moving between cells for which
+/// `passable(from, to)` holds.
+pub fn bfs<T>(
+    grid: &Grid<T>,
+    start: Coord,
+    with_diagonals: bool,
+    is_goal: impl FnMut(Coord) -> bool,
+    passable: impl FnMut(Coord, Coord) -> bool,
+) -> Option<Path> {
+    let search = breadth_first(grid, start, with_diagonals, is_goal, passable);
+
+    search.goal.map(|goal| search.path(goal))
+}
+
+/// The number of steps from `start` to every reachable cell.
+pub fn bfs_distances<T>(
+    grid: &Grid<T>,
+    start: Coord,
+    with_diagonals: bool,
+    passable: impl FnMut(Coord, Coord) -> bool,
+) -> Grid<Option<u32>> {
+    breadth_first(grid, start, with_diagonals, |_| false, passable).distances
+}
+
+/// Finds the cheapest path from `start` to a cell matching `is_goal`. `cost(from, to)` is the cost
+/// of a move, or `None` if it isn't allowed.
+pub fn dijkstra<T>(
+    grid: &Grid<T>,
+    start: Coord,
+    with_diagonals: bool,
+    is_goal: impl FnMut(Coord) -> bool,
+    cost: impl FnMut(Coord, Coord) -> Option<u32>,
+) -> Option<Path> {
+    astar(grid, start, with_diagonals, is_goal, cost, |_| 0)
+}
+
+/// The cost of the cheapest path from `start` to every reachable cell.
+pub fn dijkstra_distances<T>(
+    grid: &Grid<T>,
+    start: Coord,
+    with_diagonals: bool,
+    cost: impl FnMut(Coord, Coord) -> Option<u32>,
+) -> Grid<Option<u32>> {
+    best_first(grid, start, with_diagonals, |_| false, cost, |_| 0).distances
+}
+
+/// Like `dijkstra`, guided by `heuristic`, which must never overestimate the remaining cost to the
+/// goal (the manhattan distance when every move costs at least 1, for example).
+pub fn astar<T>(
+    grid: &Grid<T>,
+    start: Coord,
+    with_diagonals: bool,
+    is_goal: impl FnMut(Coord) -> bool,
+    cost: impl FnMut(Coord, Coord) -> Option<u32>,
+    heuristic: impl FnMut(Coord) -> u32,
+) -> Option<Path> {
+    let search = best_first(grid, start, with_diagonals, is_goal, cost, heuristic);
+
+    search.goal.map(|goal| search.path(goal))
+}
+
+struct Search {
+    distances: Grid<Option<u32>>,
+    previous: Grid<Option<Coord>>,
+    goal: Option<Coord>,
+}
+
+impl Search {
+    fn new<T>(grid: &Grid<T>, start: Coord) -> Self {
+        assert!(
+            grid.contains(Point::from(start)),
+            "start {:?} is outside the grid",
+            start
+        );
+
+        let mut distances = Grid::new(grid.size, vec![None; grid.len()]);
+        distances.set(start, Some(0));
+
+        Search {
+            distances,
+            previous: Grid::new(grid.size, vec![None; grid.len()]),
+            goal: None,
+        }
+    }
+
+    fn path(&self, goal: Coord) -> Path {
+        let mut coords = vec![goal];
+        while let Some(previous) = self.previous.get(*coords.last().unwrap()) {
+            coords.push(*previous);
+        }
+        coords.reverse();
+
+        Path {
+            distance: self.distances.get(goal).unwrap(),
+            coords,
+        }
+    }
+}
+
+fn neighbors<T>(
+    grid: &Grid<T>,
+    coord: Coord,
+    with_diagonals: bool,
+) -> impl Iterator<Item = Coord> + '_ {
+    Point::from(coord)
+        .neighbors(with_diagonals)
+        .filter(|&point| grid.contains(point))
+        .filter_map(Point::to_coord)
+}
+
+fn breadth_first<T>(
+    grid: &Grid<T>,
+    start: Coord,
+    with_diagonals: bool,
+    mut is_goal: impl FnMut(Coord) -> bool,
+    mut passable: impl FnMut(Coord, Coord) -> bool,
+) -> Search {
+    let mut search = Search::new(grid, start);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((coord, distance)) = queue.pop_front() {
+        if is_goal(coord) {
+            search.goal = Some(coord);
+            break;
+        }
+
+        for next in neighbors(grid, coord, with_diagonals) {
+            if search.distances.get(next).is_none() && passable(coord, next) {
+                search.distances.set(next, Some(distance + 1));
+                search.previous.set(next, Some(coord));
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    search
+}
+
+fn best_first<T>(
+    grid: &Grid<T>,
+    start: Coord,
+    with_diagonals: bool,
+    mut is_goal: impl FnMut(Coord) -> bool,
+    mut cost: impl FnMut(Coord, Coord) -> Option<u32>,
+    mut heuristic: impl FnMut(Coord) -> u32,
+) -> Search {
+    let mut search = Search::new(grid, start);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(start), 0, start))]);
+
+    while let Some(Reverse((_, distance, coord))) = queue.pop() {
+        // Skip entries superseded by a cheaper path found after they were queued.
+        if *search.distances.get(coord) != Some(distance) {
+            continue;
+        }
+
+        if is_goal(coord) {
+            search.goal = Some(coord);
+            break;
+        }
+
+        for next in neighbors(grid, coord, with_diagonals) {
+            // Moves that would overflow the distance are as good as impossible.
+            let Some(next_distance) = cost(coord, next).and_then(|step| distance.checked_add(step))
+            else {
+                continue;
+            };
+
+            if search
+                .distances
+                .get(next)
+                .is_none_or(|known| next_distance < known)
+            {
+                search.distances.set(next, Some(next_distance));
+                search.previous.set(next, Some(coord));
+                queue.push(Reverse((
+                    next_distance.saturating_add(heuristic(next)),
+                    next_distance,
+                    next,
+                )));
+            }
+        }
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input_grid;
+
+    use super::*;
+
+    fn create_maze() -> Grid<char> {
+        input_grid(
+            "
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#G",
+        )
+    }
+
+    fn open(maze: &Grid<char>) -> impl Fn(Coord, Coord) -> bool + '_ {
+        move |_, to| *maze.get(to) != '#'
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = create_maze();
+
+        let path = bfs(&maze, (0, 0), false, |coord| coord == (7, 4), open(&maze)).unwrap();
+        assert_eq!(path.distance, 15);
+        assert_eq!(path.coords.len(), 16);
+        assert_eq!(path.coords.first(), Some(&(0, 0)));
+        assert_eq!(path.coords.last(), Some(&(7, 4)));
+        assert!(path
+            .coords
+            .windows(2)
+            .all(|step| Point::from(step[0]).manhattan(Point::from(step[1])) == 1));
+
+        let path = bfs(&maze, (0, 0), true, |coord| coord == (7, 4), open(&maze)).unwrap();
+        assert_eq!(path.distance, 8);
+
+        assert_eq!(bfs(&maze, (0, 0), false, |_| false, open(&maze)), None);
+    }
+
+    #[test]
+    fn test_bfs_distances() {
+        let maze = create_maze();
+        let distances = bfs_distances(&maze, (0, 0), false, open(&maze));
+
+        assert_eq!(distances.get((0, 0)), &Some(0));
+        assert_eq!(distances.get((2, 2)), &Some(4));
+        assert_eq!(distances.get((3, 0)), &None);
+        assert_eq!(distances.get((7, 4)), &Some(15));
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        let maze = create_maze();
+        // Walking through a wall costs 10.
+        let cost = |_, to| Some(if *maze.get(to) == '#' { 10 } else { 1 });
+        let is_goal = |coord| coord == (7, 4);
+
+        let path = dijkstra(&maze, (0, 0), false, is_goal, cost).unwrap();
+        assert_eq!(path.distance, 15);
+        assert_eq!(
+            path.coords
+                .iter()
+                .filter(|&&coord| *maze.get(coord) == '#')
+                .count(),
+            0
+        );
+
+        let heuristic = |coord| Point::from(coord).manhattan(Point::new(7, 4)) as u32;
+        let path = astar(&maze, (0, 0), false, is_goal, cost, heuristic).unwrap();
+        assert_eq!(path.distance, 15);
+
+        let distances = dijkstra_distances(&maze, (0, 0), false, cost);
+        assert_eq!(distances.get((3, 0)), &Some(12));
+        assert_eq!(distances.get((7, 4)), &Some(15));
+    }
+
+    #[test]
+    fn test_dijkstra_overflow() {
+        let maze = create_maze();
+        // A wall next to the start's neighbors is reached at exactly `u32::MAX`, any further
+        // one would overflow.
+        let cost = |_, to| {
+            Some(if *maze.get(to) == '#' {
+                u32::MAX - 1
+            } else {
+                1
+            })
+        };
+
+        let distances = dijkstra_distances(&maze, (0, 0), false, cost);
+        assert_eq!(distances.get((7, 4)), &Some(15));
+        assert_eq!(distances.get((1, 1)), &Some(u32::MAX));
+        assert_eq!(distances.get((3, 0)), &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "start (8, 0) is outside the grid")]
+    fn test_start_outside_grid() {
+        let maze = create_maze();
+        bfs_distances(&maze, (8, 0), false, open(&maze));
+    }
+}
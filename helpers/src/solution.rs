@@ -0,0 +1,13 @@
+use std::fmt::Display;
+
+/// A day's puzzle: the input is parsed once and both parts solve the parsed value.
+pub trait Solution {
+    type Input<'a>: Send + Sync;
+    type Answer: Display + Send;
+
+    fn parse(input: &str) -> Self::Input<'_>;
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer;
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer;
+}
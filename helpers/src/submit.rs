@@ -0,0 +1,490 @@
+use std::fmt::{Display, Formatter};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+use std::{fs, io};
+
+use crate::history::unix_time;
+use crate::{Answers, Client, ClientError, ANSWERS_FILE};
+
+pub const SUBMISSIONS_FILE: &str = "submissions.log";
+
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    Wait(Duration),
+    AlreadySolved,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub outcome: Outcome,
+    pub cooldown: Duration,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    pub time: u64,
+    pub part: String,
+    pub answer: String,
+    pub outcome: Outcome,
+    pub cooldown: Duration,
+}
+
+#[derive(Debug, Default)]
+pub struct SubmissionLog {
+    path: PathBuf,
+    entries: Vec<LogEntry>,
+}
+
+impl Client {
+    pub fn submit(&self, day: u8, level: u8, answer: &str) -> Result<Submission, ClientError> {
+        let response = self
+            .agent
+            .post(&self.url(day, "/answer"))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &level.to_string()), ("answer", answer)]);
+
+        let html = crate::client::read_response(response)?;
+
+        parse_submission(&html).ok_or_else(|| ClientError::UnexpectedResponse(article_text(&html)))
+    }
+}
+
+pub fn submit_answer<P: AsRef<Path>>(
+    client: &Client,
+    day_dir: P,
+    day: u8,
+    part: &str,
+    answer: &str,
+    now: SystemTime,
+) -> Result<Submission, ClientError> {
+    let day_dir = day_dir.as_ref();
+    let level = part
+        .strip_prefix("part")
+        .and_then(|level| level.parse().ok())
+        .filter(|level| *level == 1 || *level == 2)
+        .ok_or_else(|| ClientError::InvalidPart(part.to_string()))?;
+
+    let answers_path = day_dir.join(ANSWERS_FILE);
+    let mut answers = Answers::load(&answers_path)
+        .map_err(|error| ClientError::Io(answers_path.clone(), error))?;
+    if let Some(known) = answers.get(part) {
+        return Err(ClientError::AlreadySolved(known.to_string()));
+    }
+
+    let log_path = day_dir.join(SUBMISSIONS_FILE);
+    let mut log =
+        SubmissionLog::load(&log_path).map_err(|error| ClientError::Io(log_path.clone(), error))?;
+    if let Some(remaining) = log.cooldown_remaining(now) {
+        return Err(ClientError::Cooldown(remaining));
+    }
+
+    let submission = client.submit(day, level, answer)?;
+
+    log.append(LogEntry {
+        time: unix_time(now),
+        part: part.to_string(),
+        answer: answer.to_string(),
+        outcome: submission.outcome,
+        cooldown: submission.cooldown,
+    })
+    .map_err(|error| ClientError::Io(log_path, error))?;
+
+    if submission.outcome == Outcome::Correct {
+        answers.set(part, answer);
+        answers
+            .save(&answers_path)
+            .map_err(|error| ClientError::Io(answers_path, error))?;
+    }
+
+    Ok(submission)
+}
+
+pub fn parse_submission(html: &str) -> Option<Submission> {
+    let message = article_text(html);
+
+    let (outcome, cooldown) = if message.contains("That's the right answer") {
+        (Outcome::Correct, Duration::ZERO)
+    } else if message.contains("That's not the right answer") {
+        let outcome = if message.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if message.contains("your answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Incorrect
+        };
+
+        (
+            outcome,
+            parse_retry_wait(&message).unwrap_or(DEFAULT_COOLDOWN),
+        )
+    } else if message.contains("You gave an answer too recently") {
+        let wait = parse_left_to_wait(&message).unwrap_or(DEFAULT_COOLDOWN);
+        (Outcome::Wait(wait), wait)
+    } else if message.contains("You don't seem to be solving the right level") {
+        (Outcome::AlreadySolved, Duration::ZERO)
+    } else {
+        return None;
+    };
+
+    Some(Submission {
+        outcome,
+        cooldown,
+        message,
+    })
+}
+
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for char in article.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            char if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+
+    text.replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// "You have 1m 5s left to wait."
+fn parse_left_to_wait(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (amount, _) = rest.split_once(" left to wait")?;
+
+    amount
+        .split_whitespace()
+        .map(|part| {
+            let (value, unit) = part.split_at(part.find(|char: char| !char.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+// "please wait one minute before trying again" or "please wait 5 minutes before trying again"
+fn parse_retry_wait(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("please wait ")?;
+    let (amount, _) = rest.split_once(" before trying again")?;
+    let (value, unit) = amount.split_once(' ')?;
+
+    let value = match value {
+        "one" => 1,
+        value => value.parse().ok()?,
+    };
+
+    match unit.trim_end_matches('s') {
+        "minute" => Some(Duration::from_secs(value * 60)),
+        "second" => Some(Duration::from_secs(value)),
+        _ => None,
+    }
+}
+
+impl SubmissionLog {
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(error),
+        };
+
+        let entries = content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.parse()
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(SubmissionLog { path, entries })
+    }
+
+    pub fn entries(&self) -> &[LogEntry] {
+        &self.entries
+    }
+
+    pub fn append(&mut self, entry: LogEntry) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", entry)?;
+
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    pub fn cooldown_remaining(&self, now: SystemTime) -> Option<Duration> {
+        let last = self.entries.last()?;
+        let until = last.time + last.cooldown.as_secs();
+
+        until
+            .checked_sub(unix_time(now))
+            .filter(|remaining| *remaining > 0)
+            .map(Duration::from_secs)
+    }
+}
+
+impl Display for LogEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}",
+            self.time,
+            self.part,
+            self.answer,
+            self.outcome,
+            self.cooldown.as_secs()
+        )
+    }
+}
+
+impl FromStr for LogEntry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || format!("Invalid submission entry `{}`", s);
+        let fields: Vec<&str> = s.split('\t').collect();
+
+        if fields.len() != 5 {
+            return Err(error());
+        }
+
+        let cooldown = Duration::from_secs(fields[4].parse().map_err(|_| error())?);
+
+        Ok(LogEntry {
+            time: fields[0].parse().map_err(|_| error())?,
+            part: fields[1].to_string(),
+            answer: fields[2].to_string(),
+            outcome: match fields[3] {
+                "wait" => Outcome::Wait(cooldown),
+                outcome => outcome.parse().map_err(|_| error())?,
+            },
+            cooldown,
+        })
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too-high"),
+            Outcome::TooLow => write!(f, "too-low"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::Wait(_) => write!(f, "wait"),
+            Outcome::AlreadySolved => write!(f, "already-solved"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "too-high" => Ok(Outcome::TooHigh),
+            "too-low" => Ok(Outcome::TooLow),
+            "incorrect" => Ok(Outcome::Incorrect),
+            "already-solved" => Ok(Outcome::AlreadySolved),
+            _ => Err(format!("Unknown outcome `{}`", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::time::UNIX_EPOCH;
+
+    use crate::client::tests::serve;
+
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            message
+        )
+    }
+
+    fn temp_day_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("helpers-submit-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        dir
+    }
+
+    #[test]
+    fn test_parse_correct() {
+        let submission = parse_submission(&page(
+            "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.",
+        ))
+        .unwrap();
+
+        assert_eq!(submission.outcome, Outcome::Correct);
+        assert_eq!(submission.cooldown, Duration::ZERO);
+        assert_eq!(
+            submission.message,
+            "That's the right answer! You are one gold star closer."
+        );
+    }
+
+    #[test]
+    fn test_parse_wrong_answers() {
+        let too_high = parse_submission(&page(
+            "That's not the right answer; your answer is too high.  Please wait one minute before trying again.",
+        ))
+        .unwrap();
+        let too_low = parse_submission(&page(
+            "That's not the right answer; your answer is too low.  please wait 5 minutes before trying again.",
+        ))
+        .unwrap();
+        let incorrect = parse_submission(&page("That's not the right answer.")).unwrap();
+
+        assert_eq!(too_high.outcome, Outcome::TooHigh);
+        assert_eq!(too_high.cooldown, Duration::from_secs(60));
+        assert_eq!(too_low.outcome, Outcome::TooLow);
+        assert_eq!(too_low.cooldown, Duration::from_secs(300));
+        assert_eq!(incorrect.outcome, Outcome::Incorrect);
+        assert_eq!(incorrect.cooldown, DEFAULT_COOLDOWN);
+    }
+
+    #[test]
+    fn test_parse_wait_and_already_solved() {
+        let wait = parse_submission(&page(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.",
+        ))
+        .unwrap();
+        let solved = parse_submission(&page(
+            "You don't seem to be solving the right level.  Did you already complete it?",
+        ))
+        .unwrap();
+
+        assert_eq!(wait.outcome, Outcome::Wait(Duration::from_secs(65)));
+        assert_eq!(wait.cooldown, Duration::from_secs(65));
+        assert_eq!(solved.outcome, Outcome::AlreadySolved);
+        assert_eq!(parse_submission(&page("Something else")), None);
+    }
+
+    #[test]
+    fn test_log_entry_round_trip() {
+        let entry = LogEntry {
+            time: 1701417600,
+            part: "part1".to_string(),
+            answer: "42".to_string(),
+            outcome: Outcome::Wait(Duration::from_secs(30)),
+            cooldown: Duration::from_secs(30),
+        };
+
+        assert_eq!(entry.to_string(), "1701417600\tpart1\t42\twait\t30");
+        assert_eq!(entry.to_string().parse(), Ok(entry));
+        assert!("1701417600\tpart1".parse::<LogEntry>().is_err());
+    }
+
+    #[test]
+    fn test_submit_records_correct_answer() {
+        let dir = temp_day_dir("correct");
+        let (base_url, server) = serve("200 OK", &page("That's the right answer!"));
+        let client = Client::new("secret").with_base_url(&base_url);
+        let now = UNIX_EPOCH + Duration::from_secs(1000);
+
+        let submission = submit_answer(&client, &dir, 3, "part2", "4361", now).unwrap();
+
+        assert_eq!(submission.outcome, Outcome::Correct);
+        let request = server.join().unwrap();
+        assert!(request
+            .head
+            .starts_with("POST /2023/day/3/answer HTTP/1.1\r\n"));
+        assert!(request.head.contains("Cookie: session=secret\r\n"));
+        assert_eq!(request.body, "level=2&answer=4361");
+
+        assert_eq!(
+            Answers::load(dir.join(ANSWERS_FILE)).unwrap().get("part2"),
+            Some("4361")
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join(SUBMISSIONS_FILE)).unwrap(),
+            "1000\tpart2\t4361\tcorrect\t0\n"
+        );
+        assert!(matches!(
+            submit_answer(&client, &dir, 3, "part2", "4361", now),
+            Err(ClientError::AlreadySolved(answer)) if answer == "4361"
+        ));
+    }
+
+    #[test]
+    fn test_submit_honors_cooldown() {
+        let dir = temp_day_dir("cooldown");
+        let (base_url, server) = serve(
+            "200 OK",
+            &page("That's not the right answer; your answer is too low. Please wait one minute before trying again."),
+        );
+        let client = Client::new("secret").with_base_url(&base_url);
+        let now = UNIX_EPOCH + Duration::from_secs(1000);
+
+        let submission = submit_answer(&client, &dir, 3, "part1", "1", now).unwrap();
+        server.join().unwrap();
+
+        assert_eq!(submission.outcome, Outcome::TooLow);
+        assert!(matches!(
+            submit_answer(&client, &dir, 3, "part1", "2", now + Duration::from_secs(45)),
+            Err(ClientError::Cooldown(remaining)) if remaining == Duration::from_secs(15)
+        ));
+        assert_eq!(
+            SubmissionLog::load(dir.join(SUBMISSIONS_FILE))
+                .unwrap()
+                .cooldown_remaining(now + Duration::from_secs(60)),
+            None
+        );
+        assert_eq!(
+            Answers::load(dir.join(ANSWERS_FILE)).unwrap(),
+            Answers::default()
+        );
+    }
+
+    #[test]
+    fn test_submit_invalid_part() {
+        let client = Client::new("secret").with_base_url("http://127.0.0.1:9");
+
+        assert!(matches!(
+            submit_answer(&client, temp_day_dir("invalid"), 1, "part3", "1", SystemTime::now()),
+            Err(ClientError::InvalidPart(part)) if part == "part3"
+        ));
+    }
+}
@@ -0,0 +1,116 @@
+//! Deterministic clocks, output capture and report assertions for testing days, enabled with the
+//! `test-support` feature.
+
+use std::io::{self, Write};
+
+use crate::{RunReport, Status};
+
+pub use crate::instant::fake::{with_fake_elapsed, with_fake_samples, Instant as FakeInstant};
+pub use crate::instant::BasicInstant;
+
+/// A writer that keeps everything written to it, for inspecting runner output.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Capture {
+    buffer: Vec<u8>,
+}
+
+impl Capture {
+    pub fn new() -> Self {
+        Capture::default()
+    }
+
+    pub fn output(&self) -> &str {
+        std::str::from_utf8(&self.buffer).expect("Not UTF-8")
+    }
+
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+}
+
+impl Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn assert_answers(reports: &[RunReport], expected: &[(&str, &str)]) {
+    let actual: Vec<(&str, &str)> = reports
+        .iter()
+        .map(|report| (report.part.as_str(), report.answer.as_str()))
+        .collect();
+
+    assert_eq!(actual, expected, "Unexpected answers");
+}
+
+#[track_caller]
+pub fn assert_status(report: &RunReport, expected: &Status) {
+    assert_eq!(
+        &report.status,
+        expected,
+        "Unexpected status for {}",
+        report.name()
+    );
+}
+
+#[track_caller]
+pub fn assert_no_failures(reports: &[RunReport]) {
+    let failures: Vec<String> = reports
+        .iter()
+        .filter(|report| report.is_failure())
+        .map(|report| report.to_string())
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "Failed parts:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_capture() {
+        let mut capture = Capture::new();
+
+        write!(capture, "part1: {}", 42).unwrap();
+        assert_eq!(capture.output(), "part1: 42");
+
+        capture.clear();
+        assert_eq!(capture.output(), "");
+    }
+
+    #[test]
+    fn test_assertions() {
+        let mut first = RunReport::new(Some(1), "part1", "142".to_string(), Duration::ZERO);
+        first.status = Status::Correct;
+        let second = RunReport::new(Some(1), "part2", "281".to_string(), Duration::ZERO);
+        let reports = [first, second];
+
+        assert_answers(&reports, &[("part1", "142"), ("part2", "281")]);
+        assert_status(&reports[0], &Status::Correct);
+        assert_no_failures(&reports);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed parts:\nday1 part1: 0 (0.00ns) ✗ expected 142")]
+    fn test_assert_no_failures() {
+        let mut report = RunReport::new(Some(1), "part1", "0".to_string(), Duration::ZERO);
+        report.status = Status::Wrong {
+            expected: "142".to_string(),
+        };
+
+        assert_no_failures(&[report]);
+    }
+}